@@ -0,0 +1,1481 @@
+//! The declarations that `def_mod!` is made of, which can be parsed, or put together by hand, and turned into the checks with `to_token_stream()`.
+
+use super::*;
+
+///
+/// A module declaration: `mod my_mod`
+/// 
+/// One of the differences between this and a normal mod decl is
+/// the attributes can declare a path literal:
+/// ```rust,ignore
+/// #[cfg(target_os = "windows")] = "my_mod/win/mod.rs"
+/// mod my_mod;
+/// ```
+/// 
+/// The other is that it can declare a body.
+/// The body contains methods/types that the module needs to export.
+/// If the module doesn't export those symbols, you will get a compiler error.
+/// 
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct ModuleDecl {
+	/// The attributes that go on every declaration of the module, along with the path that some of them declare.
+	pub attrs: Vec<(Attribute, Option<PathValue>)>,
+	/// The attributes that only go on the declarations: `#[mod: doc(hidden)]`
+	pub mod_attrs: Vec<Attribute>,
+	/// The attributes that only go on the load function: `#[load: allow(unused)]`
+	pub load_attrs: Vec<Attribute>,
+	pub vis: Visibility,
+	pub mod_token: Token![mod],
+	pub ident: Ident,
+	pub body: ModuleBody,
+}
+
+/// What an attribute was targeted at, instead of the default, which is every declaration of the module.
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+enum AttrTarget {
+	/// `#[mod: doc(hidden)]`
+	Mod,
+	/// `#[load: allow(unused)]`
+	Load,
+}
+
+/// The path literal of an attribute, which can also be read from an environment variable while expanding.
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum PathValue {
+	/// `#[cfg(unix)] = "sys/nix.rs"`
+	Lit(LitStr),
+	/// `#[cfg(feature = "gen")] = env!("MY_GEN_PATH")`
+	Env(LitStr),
+	/// `#[cfg(unix)] = ["vendor/sys.rs", "sys.rs"]`
+	Candidates(Vec<LitStr>),
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum ModuleBody {
+	Content((token::Brace, Vec<(Visibility, DeclItem)>)),
+	Terminated(Token![;]),
+}
+
+impl ModuleDecl {
+	/// A module without any attributes or a body, as if it were declared with `mod my_mod;`
+	pub fn new(vis: Visibility, ident: Ident) -> Self {
+		let span = ident.span();
+		ModuleDecl {
+			attrs: vec![],
+			mod_attrs: vec![],
+			load_attrs: vec![],
+			vis,
+			mod_token: Token![mod](span),
+			ident,
+			body: ModuleBody::Terminated(Token![;](span)),
+		}
+	}
+
+	/// Adds an item to the module's body, which gives it one if it was declared without.
+	pub fn push_item(&mut self, vis: Visibility, item: DeclItem) {
+		if let ModuleBody::Terminated(semi_token) = self.body {
+			self.body = ModuleBody::Content((token::Brace(semi_token.span), vec![]));
+		}
+		if let ModuleBody::Content((_brace, ref mut body)) = self.body {
+			body.push((vis, item));
+		}
+	}
+
+	fn parse_all(input: ParseStream) -> Result<Vec<ModuleDecl>> {
+		let mut decls = vec![];
+		while !input.is_empty() {
+			if input.peek(kw::cfg) && input.peek2(token::Paren) {
+				decls.extend(ModuleDecl::parse_cfg_block(input)?);
+			} else {
+				decls.push(input.parse()?);
+			}
+		}
+		Ok(decls)
+	}
+
+	/// Parses a block of modules that share a cfg: `cfg(feature = "advanced") { mod a; mod b; }`
+	/// The cfg is given to each of the modules, as if it was declared on them.
+	fn parse_cfg_block(input: ParseStream) -> Result<Vec<ModuleDecl>> {
+		input.parse::<kw::cfg>()?;
+		let predicate;
+		parenthesized!(predicate in input);
+		let predicate: TokenStream = predicate.parse()?;
+		let content;
+		braced!(content in input);
+		let mut decls = ModuleDecl::parse_all(&content)?;
+		for decl in &mut decls {
+			let attr: Attribute = parse_quote!(#[cfg(#predicate)]);
+			decl.attrs.insert(0, (attr, None));
+		}
+		Ok(decls)
+	}
+}
+
+mod kw {
+	syn::custom_keyword!(align);
+	syn::custom_keyword!(cfg);
+	syn::custom_keyword!(env);
+	syn::custom_keyword!(field);
+	syn::custom_keyword!(load);
+	syn::custom_keyword!(op);
+	syn::custom_keyword!(sig);
+	syn::custom_keyword!(size);
+	syn::custom_keyword!(tuple);
+	syn::custom_keyword!(unit);
+}
+
+impl Parse for PathValue {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if input.peek(LitStr) {
+			return input.parse().map(PathValue::Lit);
+		}
+		if input.peek(token::Bracket) {
+			let content;
+			let bracket = bracketed!(content in input);
+			let candidates: Punctuated<LitStr, Token![,]> = content.parse_terminated(<LitStr as Parse>::parse)?;
+			if candidates.is_empty() {
+				return Err(Error::new(bracket.span, "Expected at least one path."));
+			}
+			return Ok(PathValue::Candidates(candidates.into_iter().collect()));
+		}
+		input.parse::<kw::env>()?;
+		input.parse::<Token![!]>()?;
+		let content;
+		parenthesized!(content in input);
+		content.parse().map(PathValue::Env)
+	}
+}
+
+impl Parse for ModuleDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let mut attrs = vec![];
+		let mut mod_attrs = vec![];
+		let mut load_attrs = vec![];
+		while input.peek(Token![#]) {
+			let (target, attr) = parse_outer_attr(input)?;
+			if let Some(target) = target {
+				if is_attr(&attr, "cfg") {
+					return Err(Error::new(attr.span(), "A #[cfg] has to gate both the module and its checks, so it can't be targeted at one of them."));
+				}
+				if input.peek(Token![=]) {
+					return Err(Error::new(attr.span(), "A targeted attribute can't declare a path, as it only applies to one of the declaration or the load function."));
+				}
+				match target {
+					AttrTarget::Mod => mod_attrs.push(attr),
+					AttrTarget::Load => load_attrs.push(attr),
+				}
+				continue;
+			}
+			let path = if input.peek(Token![=]) {
+				input.parse::<Token![=]>()?;
+				Some(input.parse()?)
+			} else {
+				None
+			};
+			attrs.push((attr, path));
+		}
+		let vis = input.parse()?;
+		let mod_token = input.parse()?;
+		let ident = input.parse()?;
+		let body = if input.peek(Token![;]) {
+			ModuleBody::Terminated(input.parse()?)
+		} else {
+			let content;
+			let brace = braced!(content in input);
+			let mut items = vec![];
+			while more_items(&content)? {
+				items.push(DeclItem::parse_with_vis(&content)?);
+			}
+			ModuleBody::Content((brace, items))
+		};
+		Ok(ModuleDecl {
+			attrs,
+			mod_attrs,
+			load_attrs,
+			vis,
+			mod_token,
+			ident,
+			body,
+		})
+	}
+}
+
+/// Parses the attributes that are given to the invocation itself, `#![manifest = "..."]`, followed by each of the modules.
+pub(crate) fn parse_invocation(input: ParseStream) -> Result<(Vec<Attribute>, Vec<ModuleDecl>)> {
+	let attrs = input.call(Attribute::parse_inner)?;
+	let declarations = ModuleDecl::parse_all(input)?;
+	Ok((attrs, declarations))
+}
+
+/// Parses a single outer attribute, as each of a module's attributes can be followed by a path.
+/// It can also be targeted at either the declaration or the load function, which comes before the attribute itself.
+fn parse_outer_attr(input: ParseStream) -> Result<(Option<AttrTarget>, Attribute)> {
+	let content;
+	let pound_token = input.parse()?;
+	let bracket_token = bracketed!(content in input);
+	let target = if content.peek(Token![mod]) && content.peek2(Token![:]) {
+		content.parse::<Token![mod]>()?;
+		content.parse::<Token![:]>()?;
+		Some(AttrTarget::Mod)
+	} else if content.peek(kw::load) && content.peek2(Token![:]) && !content.peek2(Token![::]) {
+		content.parse::<kw::load>()?;
+		content.parse::<Token![:]>()?;
+		Some(AttrTarget::Load)
+	} else {
+		None
+	};
+	let attr = Attribute {
+		pound_token,
+		style: AttrStyle::Outer,
+		bracket_token,
+		path: content.call(Path::parse_mod_style)?,
+		tts: content.parse()?,
+	};
+	Ok((target, attr))
+}
+
+/// Parses a method the same way as a trait would, except that the parameters can end with `...`, like a foreign function.
+fn parse_method(input: ParseStream) -> Result<TraitItemMethod> {
+	match parse_qualified_method(input)? {
+		(Some(type_name), _method_item) => Err(Error::new(type_name.span(), "Only a function of a module can be qualified by a type. [Hint: Remove the `Type::`.]")),
+		(None, method_item) => match method_item.attrs.iter().find(|attr| is_attr(attr, "as_const")) {
+			Some(attr) => Err(Error::new(attr.span(), "Only a function of a module can be paired with a const. [Hint: Remove the `as const ...`.]")),
+			None => Ok(method_item),
+		},
+	}
+}
+
+/// Parses a method that can have a type in front of its name: `fn MyStruct::with_capacity(_: usize) -> MyStruct;`
+fn parse_qualified_method(input: ParseStream) -> Result<(Option<Ident>, TraitItemMethod)> {
+	let attrs = input.call(Attribute::parse_outer)?;
+	let constness = input.parse()?;
+	let asyncness = input.parse()?;
+	let unsafety = input.parse()?;
+	let abi = input.parse()?;
+	let fn_token = input.parse()?;
+	let type_name = if input.peek(Ident) && input.peek2(Token![::]) {
+		let type_name = input.parse()?;
+		input.parse::<Token![::]>()?;
+		Some(type_name)
+	} else {
+		None
+	};
+	let ident: Ident = input.parse()?;
+	let generics: Generics = input.parse()?;
+
+	let content;
+	let paren_token = parenthesized!(content in input);
+	let mut inputs = Punctuated::new();
+	while !content.is_empty() && !content.peek(Token![...]) {
+		inputs.push_value(content.parse()?);
+		if content.is_empty() {
+			break;
+		}
+		inputs.push_punct(content.parse()?);
+	}
+	let variadic = if inputs.empty_or_trailing() {
+		content.parse()?
+	} else {
+		None
+	};
+	if !content.is_empty() {
+		return Err(content.error("The `...` has to be the last parameter."));
+	}
+
+	let output = input.parse()?;
+	let where_clause = input.parse()?;
+	// A const that points at the function, `as const HANDLER`, is carried as an attribute, the same as the other options: `#[as_const(HANDLER)]`
+	let mut attrs = attrs;
+	if input.peek(Token![as]) {
+		input.parse::<Token![as]>()?;
+		input.parse::<Token![const]>()?;
+		let const_name: Ident = input.parse()?;
+		let attr = quote_spanned!(const_name.span() => #[as_const(#const_name)]);
+		attrs.extend(Attribute::parse_outer.parse2(attr)?);
+	}
+	let (default, semi_token) = if input.peek(token::Brace) {
+		(Some(input.parse()?), None)
+	} else {
+		(None, Some(parse_semi(input, ident.span())?))
+	};
+	Ok((type_name, TraitItemMethod {
+		attrs,
+		sig: MethodSig {
+			constness,
+			asyncness,
+			unsafety,
+			abi,
+			ident,
+			decl: FnDecl {
+				fn_token,
+				generics: Generics {
+					where_clause,
+					..generics
+				},
+				paren_token,
+				inputs,
+				variadic,
+				output,
+			},
+		},
+		default,
+		semi_token,
+	}))
+}
+
+/// Checks if there's another item in a body, once the separators in front of it are skipped.
+/// A `;` after an item with a body isn't needed, but it's easy to write out of habit, `type MyStruct {};`, so it's left alone.
+/// A `,` reads as if the items were a list, which they aren't, so it's reported before it's skipped.
+fn more_items(input: ParseStream) -> Result<bool> {
+	loop {
+		if input.peek(Token![;]) {
+			input.parse::<Token![;]>()?;
+		} else if input.peek(Token![,]) {
+			let comma: Token![,] = input.parse()?;
+			comma.spans[0]
+				.error("Items aren't separated by a `,`. [Hint: Remove the `,`.]")
+				.emit();
+		} else {
+			return Ok(!input.is_empty());
+		}
+	}
+}
+
+/// Parses the `;` at the end of an item, where a `,` or a missing one is reported instead of failing the whole invocation.
+/// Either way, the rest of the body is still parsed, so any other mistakes are reported along with it.
+fn parse_semi(input: ParseStream, item_span: Span) -> Result<Token![;]> {
+	if input.peek(Token![;]) {
+		return input.parse();
+	}
+	if input.peek(Token![,]) {
+		let comma: Token![,] = input.parse()?;
+		comma.spans[0]
+			.error("An item is ended with a `;`, not a `,`. [Hint: Replace the `,` with `;`.]")
+			.emit();
+		return Ok(Token![;](comma.spans[0]));
+	}
+	// Anything that could start another item means that the `;` was left out, and anything else is a mistake in the item itself.
+	if input.is_empty() || input.peek(Token![#]) || input.cursor().ident().is_some() {
+		item_span
+			.error("Expected a `;` at the end of this item. [Hint: Every item ends with one, unless it has a body.]")
+			.emit();
+		return Ok(Token![;](item_span));
+	}
+	input.parse()
+}
+
+/// Looks past the attributes and visibility of an item, to see if it's a module.
+fn peek_module(input: ParseStream) -> bool {
+	fn skip(input: ParseStream) -> Result<()> {
+		while input.peek(Token![#]) {
+			parse_outer_attr(input)?;
+			if input.peek(Token![=]) {
+				input.parse::<Token![=]>()?;
+				input.parse::<PathValue>()?;
+			}
+		}
+		input.parse::<Visibility>()?;
+		Ok(())
+	}
+	let ahead = input.fork();
+	skip(&ahead).is_ok() && ahead.peek(Token![mod])
+}
+
+/// Looks past the name and generics of a type declaration, to see if it's an alias.
+fn peek_alias(input: ParseStream) -> bool {
+	fn skip(input: ParseStream) -> Result<()> {
+		input.parse::<Token![type]>()?;
+		input.parse::<Ident>()?;
+		input.parse::<Generics>()?;
+		Ok(())
+	}
+	let ahead = input.fork();
+	skip(&ahead).is_ok() && ahead.peek(Token![=])
+}
+
+/// Checks if the next item is a method, which can start with a couple of different keywords.
+fn peek_method(lookahead: &Lookahead1) -> bool {
+	lookahead.peek(Token![fn])
+		|| lookahead.peek(Token![const])
+		|| lookahead.peek(Token![async])
+		|| lookahead.peek(Token![unsafe])
+		|| lookahead.peek(Token![extern])
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[allow(clippy::large_enum_variant)]
+pub enum DeclItem {
+	Method(TraitItemMethod),
+	Type(TypeDecl),
+	Trait(TraitDecl),
+	Enum(EnumDecl),
+	Union(UnionDecl),
+	Const(ConstDecl),
+	Static(StaticDecl),
+	Use(UseDecl),
+	Macro(MacroDecl),
+	Extern(ExternDecl),
+	Impl(ImplDecl),
+	Alias(AliasDecl),
+	Module(ModuleDecl),
+	AssocFn(AssocFnDecl),
+	Sig(SigDecl),
+	SigFn(SigFnDecl),
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct TypeDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub generics: Generics,
+	/// The traits that the type has to implement: `type MyStruct: Clone + Send;`
+	pub bounds: Punctuated<TypeParamBound, Token![+]>,
+	pub body: TypeDeclBody,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum TypeDeclBody {
+	Content((token::Brace, Vec<TypeItem>)),
+	Terminated(#[allow(dead_code)] Token![;]),
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[allow(clippy::large_enum_variant)]
+pub enum TypeItem {
+	Method(TraitItemMethod),
+	Const(ConstDecl),
+	Type(AssocTypeDecl),
+	Field(FieldDecl),
+	Constructor(ConstructorDecl),
+	Layout(LayoutDecl),
+	Op(OpDecl),
+}
+
+///
+/// An associated type declaration: `type Iterator::Item;`
+///
+/// If the path has a trait in front of it, it's checked against the trait's implementation,
+/// otherwise it's checked as an inherent associated type, which needs `#![feature(inherent_associated_types)]`.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct AssocTypeDecl {
+	pub attrs: Vec<Attribute>,
+	pub path: Path,
+}
+
+///
+/// A field declaration: `field timeout: Duration;`
+///
+/// The `field` keyword is optional, so `timeout: Duration;` is also accepted.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct FieldDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub ty: Type,
+}
+
+///
+/// A constructor declaration: `tuple(f64);` or `unit;`
+///
+/// It's checked by using the type's name as a value, which only works for a tuple or unit struct with visible fields.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct ConstructorDecl {
+	pub attrs: Vec<Attribute>,
+	pub span: Span,
+	/// The types of the fields, which is `None` for a unit struct.
+	pub fields: Option<Punctuated<Type, Token![,]>>,
+}
+
+///
+/// A layout declaration: `size = 16, align = 4`
+///
+/// A type's `#[repr]` can't be looked at, so its size and alignment are checked instead, which is as close as it gets.
+/// Either of them can be left out, and the `;` at the end is optional.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct LayoutDecl {
+	pub attrs: Vec<Attribute>,
+	pub entries: Punctuated<LayoutEntry, Token![,]>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct LayoutEntry {
+	/// Either `size` or `align`.
+	pub key: Ident,
+	pub value: Expr,
+}
+
+///
+/// An operator declaration: `op Add(self, Self) -> Self;`
+///
+/// It's checked against the trait's method, `<Vec3 as Add<Vec3>>::add`, so an inherent method with the same name won't pass.
+/// The trait's parameter is the type of the second parameter, unless it's given: `op Mul<f32>(self, f32) -> Self;`
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct OpDecl {
+	pub attrs: Vec<Attribute>,
+	/// The trait from `std::ops`, like `Add` or `Index`.
+	pub ident: Ident,
+	pub args: Option<AngleBracketedGenericArguments>,
+	pub inputs: Punctuated<FnArg, Token![,]>,
+	pub output: ReturnType,
+}
+
+///
+/// A type alias declaration: `type Handle = u64;`
+///
+/// Unlike a type declaration, which only checks that the type exists, the alias has to be the same type as the target.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct AliasDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub generics: Generics,
+	pub ty: Type,
+}
+
+///
+/// A trait declaration: `trait Encoder { type Out; fn encode(&self, _: &[u8]) -> usize; }`
+///
+/// The methods are checked against the trait itself, so any supertraits don't need to be declared.
+/// An associated type is checked to exist with at least the declared bounds, and `Self::Out` refers to the trait's own.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct TraitDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub body: TraitDeclBody,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub enum TraitDeclBody {
+	Content((token::Brace, Vec<TraitDeclItem>)),
+	Terminated(#[allow(dead_code)] Token![;]),
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+#[allow(clippy::large_enum_variant)]
+pub enum TraitDeclItem {
+	Method(TraitItemMethod),
+	Type(TraitItemType),
+}
+
+///
+/// An enum declaration: `enum Event { Key(u8), Resize { w: u32, h: u32 }, Quit }`
+///
+/// Every declared variant has to exist with the same fields, but the enum is free to declare more.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct EnumDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub variants: Punctuated<Variant, Token![,]>,
+}
+
+///
+/// A union declaration: `union Reg { a: u32, b: [u8; 4] }`
+///
+/// Each declared field has to exist with the same type, which is read from inside of an `unsafe` block, as it would be for a union.
+/// Much like an enum, the union is free to declare more.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct UnionDecl {
+	pub attrs: Vec<Attribute>,
+	pub union_token: Token![union],
+	pub ident: Ident,
+	pub fields: FieldsNamed,
+}
+
+///
+/// A function of a type that's declared outside of it: `fn MyStruct::with_capacity(_: usize) -> MyStruct;`
+///
+/// It's checked as if the type were a module, so `Self` isn't replaced, and it can't have a receiver.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct AssocFnDecl {
+	pub type_name: Ident,
+	pub method_item: TraitItemMethod,
+}
+
+///
+/// A named signature: `sig Codec = fn(&[u8]) -> Vec<u8>;`
+///
+/// It isn't checked on its own, it's only there for functions to be declared with, which is handy when a few of them mirror each other.
+/// A nested module can use the signatures of its parents too.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct SigDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub ty: TypeBareFn,
+}
+
+///
+/// A function that's declared with a named signature: `fn encode: Codec;`
+///
+/// It's checked the same way as if the signature were written out, with each of the parameters as `_: T`.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct SigFnDecl {
+	pub attrs: Vec<Attribute>,
+	pub fn_token: Token![fn],
+	pub ident: Ident,
+	pub sig: Ident,
+}
+
+///
+/// A constant declaration: `const PAGE_SIZE: usize;`
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct ConstDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+	pub ty: Type,
+}
+
+///
+/// A re-export declaration: `use fn thing;` or `use Thing;`
+///
+/// Only the name is checked, so it doesn't matter where the item is actually defined.
+/// With `fn`, it has to be a value, otherwise it can be anything that can be imported.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct UseDecl {
+	pub attrs: Vec<Attribute>,
+	pub fn_token: Option<Token![fn]>,
+	pub ident: Ident,
+}
+
+///
+/// A macro declaration: `macro my_macro;`
+///
+/// A macro can only be checked if it can be imported from the module, which a `macro_rules!` can't be on its own.
+/// It either has to be re-exported by the module, `pub(crate) use my_macro;`, or be a `macro` that's declared in it.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct MacroDecl {
+	pub attrs: Vec<Attribute>,
+	pub ident: Ident,
+}
+
+///
+/// An implementation declaration: `impl From<u32> for MyStruct;`
+///
+/// Only the implementation itself is checked, as the trait already says what it has to contain.
+/// A body can name the methods that the type is expected to have, which are checked through the trait:
+/// `impl Encoder for MyStruct { fn finish(self) -> u8; }`
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct ImplDecl {
+	pub attrs: Vec<Attribute>,
+	pub impl_token: Token![impl],
+	pub generics: Generics,
+	pub trait_path: Path,
+	pub self_ty: Type,
+	pub items: Vec<TraitItemMethod>,
+}
+
+///
+/// A block of foreign functions: `extern "C" { fn abs(_: c_int) -> c_int; }`
+///
+/// Each function is checked with the block's ABI, and is unsafe, just like it would be in the module's own block.
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct ExternDecl {
+	pub attrs: Vec<Attribute>,
+	pub abi: Abi,
+	pub items: Vec<(Visibility, TraitItemMethod)>,
+}
+
+///
+/// A static declaration: `static COUNTER: AtomicUsize;`
+///
+/// A mutable one has to be declared as such, and the other way around: `static mut COUNTER: u32;`
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+pub struct StaticDecl {
+	pub attrs: Vec<Attribute>,
+	pub mutability: Option<Token![mut]>,
+	pub ident: Ident,
+	pub ty: Type,
+}
+
+impl DeclItem {
+	fn parse_with_vis(input: ParseStream) -> Result<(Visibility, Self)> {
+		// A module has to come first, as its attributes can declare paths.
+		if peek_module(input) {
+			let module_item: ModuleDecl = input.parse()?;
+			return Ok((module_item.vis.clone(), DeclItem::Module(module_item)));
+		}
+		let mut attrs = input.call(Attribute::parse_outer)?;
+		let vis = input.parse()?;
+		let mut item: DeclItem = input.parse()?;
+		if let (DeclItem::Impl(_), false) = (&item, matches!(vis, Visibility::Inherited)) {
+			return Err(Error::new(vis.span(), "An implementation doesn't have a visibility, it's as visible as the trait and the type are."));
+		}
+		if let (DeclItem::Sig(_), false) = (&item, matches!(vis, Visibility::Inherited)) {
+			return Err(Error::new(vis.span(), "A signature doesn't have a visibility, it's only there to declare functions with."));
+		}
+		if let (DeclItem::AssocFn(_), false) = (&item, matches!(vis, Visibility::Inherited)) {
+			return Err(Error::new(vis.span(), "A function of a type doesn't have a visibility here, much like one that's declared inside of it."));
+		}
+		// The attributes are parsed before the visibility, so they need to be given back to the item.
+		attrs.append(item.attrs_mut());
+		*item.attrs_mut() = attrs;
+		Ok((vis, item))
+	}
+}
+
+impl Parse for DeclItem {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let ahead = input.fork();
+		ahead.call(Attribute::parse_outer)?;
+		let lookahead = ahead.lookahead1();
+		if lookahead.peek(Token![type]) && peek_alias(&ahead) {
+			input.parse().map(DeclItem::Alias)
+		} else if lookahead.peek(Token![type]) {
+			input.parse().map(DeclItem::Type)
+		} else if lookahead.peek(Token![trait]) {
+			input.parse().map(DeclItem::Trait)
+		} else if lookahead.peek(Token![enum]) {
+			input.parse().map(DeclItem::Enum)
+		} else if ahead.peek(Token![union]) && ahead.peek2(Ident) {
+			input.parse().map(DeclItem::Union)
+		} else if lookahead.peek(Token![static]) {
+			input.parse().map(DeclItem::Static)
+		} else if lookahead.peek(Token![use]) {
+			input.parse().map(DeclItem::Use)
+		} else if lookahead.peek(Token![macro]) {
+			input.parse().map(DeclItem::Macro)
+		} else if lookahead.peek(Token![impl]) {
+			input.parse().map(DeclItem::Impl)
+		} else if ahead.peek(Token![extern]) && (ahead.peek2(token::Brace) || ahead.peek3(token::Brace)) {
+			input.parse().map(DeclItem::Extern)
+		} else if ahead.peek(Token![const]) && ahead.peek2(Ident) {
+			input.parse().map(DeclItem::Const)
+		} else if ahead.peek(kw::sig) && ahead.peek2(Ident) {
+			input.parse().map(DeclItem::Sig)
+		} else if ahead.peek(Token![fn]) && ahead.peek2(Ident) && ahead.peek3(Token![:]) && !ahead.peek3(Token![::]) {
+			input.parse().map(DeclItem::SigFn)
+		} else if peek_method(&lookahead) {
+			match input.call(parse_qualified_method)? {
+				(Some(type_name), method_item) => Ok(DeclItem::AssocFn(AssocFnDecl {
+					type_name,
+					method_item,
+				})),
+				(None, method_item) => Ok(DeclItem::Method(method_item)),
+			}
+		} else {
+			Err(lookahead.error())
+		}
+	}
+}
+
+impl DeclItem {
+	pub(crate) fn ident(&self) -> &Ident {
+		match self {
+			DeclItem::Method(method_item) => &method_item.sig.ident,
+			DeclItem::AssocFn(assoc_item) => &assoc_item.method_item.sig.ident,
+			DeclItem::Sig(sig_item) => &sig_item.ident,
+			DeclItem::SigFn(sig_fn_item) => &sig_fn_item.ident,
+			DeclItem::Type(type_item) => &type_item.ident,
+			DeclItem::Trait(trait_item) => &trait_item.ident,
+			DeclItem::Enum(enum_item) => &enum_item.ident,
+			DeclItem::Union(union_item) => &union_item.ident,
+			DeclItem::Const(const_item) => &const_item.ident,
+			DeclItem::Static(static_item) => &static_item.ident,
+			DeclItem::Use(use_item) => &use_item.ident,
+			DeclItem::Macro(macro_item) => &macro_item.ident,
+			DeclItem::Extern(_) => unreachable!("An extern block doesn't have a name, only its functions do."),
+			DeclItem::Impl(_) => unreachable!("An implementation doesn't have a name."),
+			DeclItem::Alias(alias_item) => &alias_item.ident,
+			DeclItem::Module(module_item) => &module_item.ident,
+		}
+	}
+
+	pub(crate) fn attrs(&self) -> &[Attribute] {
+		match self {
+			DeclItem::Method(method_item) => &method_item.attrs,
+			DeclItem::AssocFn(assoc_item) => &assoc_item.method_item.attrs,
+			DeclItem::Sig(sig_item) => &sig_item.attrs,
+			DeclItem::SigFn(sig_fn_item) => &sig_fn_item.attrs,
+			DeclItem::Type(type_item) => &type_item.attrs,
+			DeclItem::Trait(trait_item) => &trait_item.attrs,
+			DeclItem::Enum(enum_item) => &enum_item.attrs,
+			DeclItem::Union(union_item) => &union_item.attrs,
+			DeclItem::Const(const_item) => &const_item.attrs,
+			DeclItem::Static(static_item) => &static_item.attrs,
+			DeclItem::Use(use_item) => &use_item.attrs,
+			DeclItem::Macro(macro_item) => &macro_item.attrs,
+			DeclItem::Extern(extern_item) => &extern_item.attrs,
+			DeclItem::Impl(impl_item) => &impl_item.attrs,
+			DeclItem::Alias(alias_item) => &alias_item.attrs,
+			// The attributes of a module can declare paths, so they're handled by the module itself.
+			DeclItem::Module(_) => &[],
+		}
+	}
+
+	/// Resolves the paths that start with `super`, as they're written from the module's point of view.
+	/// A nested module resolves its own, once its body is checked.
+	pub(crate) fn resolve_super(&mut self, module_path: &ModulePath) {
+		let resolver = &mut SuperResolver(module_path);
+		match self {
+			DeclItem::Method(method_item) => resolver.visit_trait_item_method_mut(method_item),
+			DeclItem::AssocFn(assoc_item) => resolver.visit_trait_item_method_mut(&mut assoc_item.method_item),
+			DeclItem::Sig(sig_item) => resolver.visit_type_bare_fn_mut(&mut sig_item.ty),
+			DeclItem::SigFn(_) => {}
+			DeclItem::Type(type_item) => {
+				resolver.visit_generics_mut(&mut type_item.generics);
+				for bound in type_item.bounds.iter_mut() {
+					resolver.visit_type_param_bound_mut(bound);
+				}
+				if let TypeDeclBody::Content((_brace, ref mut items)) = type_item.body {
+					for item in items {
+						match item {
+							TypeItem::Method(method_item) => resolver.visit_trait_item_method_mut(method_item),
+							TypeItem::Const(const_item) => resolver.visit_type_mut(&mut const_item.ty),
+							TypeItem::Type(assoc_item) => resolver.visit_path_mut(&mut assoc_item.path),
+							TypeItem::Field(field_item) => resolver.visit_type_mut(&mut field_item.ty),
+							TypeItem::Constructor(constructor_item) => {
+								for ty in constructor_item.fields.iter_mut().flatten() {
+									resolver.visit_type_mut(ty);
+								}
+							}
+							TypeItem::Layout(layout_item) => {
+								for entry in layout_item.entries.iter_mut() {
+									resolver.visit_expr_mut(&mut entry.value);
+								}
+							}
+							TypeItem::Op(op_item) => {
+								if let Some(ref mut args) = op_item.args {
+									resolver.visit_angle_bracketed_generic_arguments_mut(args);
+								}
+								for arg in op_item.inputs.iter_mut() {
+									resolver.visit_fn_arg_mut(arg);
+								}
+								resolver.visit_return_type_mut(&mut op_item.output);
+							}
+						}
+					}
+				}
+			}
+			DeclItem::Trait(trait_item) => {
+				if let TraitDeclBody::Content((_brace, ref mut items)) = trait_item.body {
+					for item in items {
+						match item {
+							TraitDeclItem::Method(method_item) => resolver.visit_trait_item_method_mut(method_item),
+							TraitDeclItem::Type(assoc_item) => resolver.visit_trait_item_type_mut(assoc_item),
+						}
+					}
+				}
+			}
+			DeclItem::Enum(enum_item) => {
+				for variant in enum_item.variants.iter_mut() {
+					resolver.visit_variant_mut(variant);
+				}
+			}
+			DeclItem::Union(union_item) => resolver.visit_fields_named_mut(&mut union_item.fields),
+			DeclItem::Const(const_item) => resolver.visit_type_mut(&mut const_item.ty),
+			DeclItem::Static(static_item) => resolver.visit_type_mut(&mut static_item.ty),
+			DeclItem::Alias(alias_item) => {
+				resolver.visit_generics_mut(&mut alias_item.generics);
+				resolver.visit_type_mut(&mut alias_item.ty);
+			}
+			DeclItem::Impl(impl_item) => {
+				resolver.visit_generics_mut(&mut impl_item.generics);
+				resolver.visit_path_mut(&mut impl_item.trait_path);
+				resolver.visit_type_mut(&mut impl_item.self_ty);
+				for method_item in impl_item.items.iter_mut() {
+					resolver.visit_trait_item_method_mut(method_item);
+				}
+			}
+			DeclItem::Extern(extern_item) => {
+				for (_vis, method_item) in extern_item.items.iter_mut() {
+					resolver.visit_trait_item_method_mut(method_item);
+				}
+			}
+			// A name on its own can't start with `super`.
+			DeclItem::Use(_) | DeclItem::Macro(_) => {}
+			DeclItem::Module(_) => {}
+		}
+	}
+
+	/// The tokens of every signature that the item declares, which is everything but the names and attributes.
+	/// A nested module's signatures are its own items, so it has none.
+	pub(crate) fn signatures(&self) -> TokenStream {
+		let mut ts = TokenStream::new();
+		match self {
+			DeclItem::Method(method_item) => method_item.sig.to_tokens(&mut ts),
+			DeclItem::AssocFn(assoc_item) => assoc_item.method_item.sig.to_tokens(&mut ts),
+			DeclItem::Sig(sig_item) => sig_item.ty.to_tokens(&mut ts),
+			// The signature is linted where it's declared.
+			DeclItem::SigFn(_) => {}
+			DeclItem::Type(type_item) => {
+				type_item.generics.to_tokens(&mut ts);
+				type_item.generics.where_clause.to_tokens(&mut ts);
+				type_item.bounds.to_tokens(&mut ts);
+				if let TypeDeclBody::Content((_brace, ref items)) = type_item.body {
+					for item in items {
+						match item {
+							TypeItem::Method(method_item) => method_item.sig.to_tokens(&mut ts),
+							TypeItem::Const(const_item) => const_item.ty.to_tokens(&mut ts),
+							TypeItem::Type(assoc_item) => assoc_item.path.to_tokens(&mut ts),
+							TypeItem::Field(field_item) => field_item.ty.to_tokens(&mut ts),
+							TypeItem::Constructor(constructor_item) => constructor_item.fields.to_tokens(&mut ts),
+							TypeItem::Layout(layout_item) => {
+								for entry in layout_item.entries.iter() {
+									entry.value.to_tokens(&mut ts);
+								}
+							}
+							TypeItem::Op(op_item) => {
+								op_item.args.to_tokens(&mut ts);
+								op_item.inputs.to_tokens(&mut ts);
+								op_item.output.to_tokens(&mut ts);
+							}
+						}
+					}
+				}
+			}
+			DeclItem::Trait(trait_item) => {
+				if let TraitDeclBody::Content((_brace, ref items)) = trait_item.body {
+					for item in items {
+						match item {
+							TraitDeclItem::Method(method_item) => method_item.sig.to_tokens(&mut ts),
+							TraitDeclItem::Type(assoc_item) => assoc_item.bounds.to_tokens(&mut ts),
+						}
+					}
+				}
+			}
+			DeclItem::Enum(enum_item) => {
+				for variant in enum_item.variants.iter() {
+					variant.fields.to_tokens(&mut ts);
+				}
+			}
+			DeclItem::Union(union_item) => union_item.fields.to_tokens(&mut ts),
+			DeclItem::Const(const_item) => const_item.ty.to_tokens(&mut ts),
+			DeclItem::Static(static_item) => {
+				static_item.mutability.to_tokens(&mut ts);
+				static_item.ty.to_tokens(&mut ts);
+			}
+			DeclItem::Alias(alias_item) => {
+				alias_item.generics.to_tokens(&mut ts);
+				alias_item.generics.where_clause.to_tokens(&mut ts);
+				alias_item.ty.to_tokens(&mut ts);
+			}
+			DeclItem::Impl(impl_item) => {
+				impl_item.generics.to_tokens(&mut ts);
+				impl_item.generics.where_clause.to_tokens(&mut ts);
+				impl_item.trait_path.to_tokens(&mut ts);
+				impl_item.self_ty.to_tokens(&mut ts);
+				for method_item in impl_item.items.iter() {
+					method_item.sig.to_tokens(&mut ts);
+				}
+			}
+			DeclItem::Extern(extern_item) => {
+				for (_vis, method_item) in extern_item.items.iter() {
+					method_item.sig.to_tokens(&mut ts);
+				}
+			}
+			DeclItem::Use(_) | DeclItem::Macro(_) | DeclItem::Module(_) => {}
+		}
+		ts
+	}
+
+	fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+		match self {
+			DeclItem::Method(method_item) => &mut method_item.attrs,
+			DeclItem::AssocFn(assoc_item) => &mut assoc_item.method_item.attrs,
+			DeclItem::Sig(sig_item) => &mut sig_item.attrs,
+			DeclItem::SigFn(sig_fn_item) => &mut sig_fn_item.attrs,
+			DeclItem::Type(type_item) => &mut type_item.attrs,
+			DeclItem::Trait(trait_item) => &mut trait_item.attrs,
+			DeclItem::Enum(enum_item) => &mut enum_item.attrs,
+			DeclItem::Union(union_item) => &mut union_item.attrs,
+			DeclItem::Const(const_item) => &mut const_item.attrs,
+			DeclItem::Static(static_item) => &mut static_item.attrs,
+			DeclItem::Use(use_item) => &mut use_item.attrs,
+			DeclItem::Macro(macro_item) => &mut macro_item.attrs,
+			DeclItem::Extern(extern_item) => &mut extern_item.attrs,
+			DeclItem::Impl(impl_item) => &mut impl_item.attrs,
+			DeclItem::Alias(alias_item) => &mut alias_item.attrs,
+			DeclItem::Module(_) => unreachable!("A module parses its own attributes."),
+		}
+	}
+}
+
+impl Parse for TypeItem {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let ahead = input.fork();
+		ahead.call(Attribute::parse_outer)?;
+		let lookahead = ahead.lookahead1();
+		if lookahead.peek(Token![type]) {
+			input.parse().map(TypeItem::Type)
+		} else if ahead.peek(Token![const]) && ahead.peek2(Ident) {
+			input.parse().map(TypeItem::Const)
+		} else if peek_method(&lookahead) {
+			input.call(parse_method).map(TypeItem::Method)
+		} else if (ahead.peek(kw::tuple) && ahead.peek2(token::Paren)) || (ahead.peek(kw::unit) && ahead.peek2(Token![;])) {
+			input.parse().map(TypeItem::Constructor)
+		} else if (ahead.peek(kw::size) || ahead.peek(kw::align)) && ahead.peek2(Token![=]) {
+			input.parse().map(TypeItem::Layout)
+		} else if ahead.peek(kw::op) && ahead.peek2(Ident) {
+			input.parse().map(TypeItem::Op)
+		} else if lookahead.peek(Ident) {
+			input.parse().map(TypeItem::Field)
+		} else {
+			Err(lookahead.error())
+		}
+	}
+}
+
+impl Parse for TypeDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![type]>()?;
+		let ident = input.parse()?;
+		let generics: Generics = input.parse()?;
+		let bounds = if input.peek(Token![:]) {
+			input.parse::<Token![:]>()?;
+			Punctuated::parse_separated_nonempty(input)?
+		} else {
+			Punctuated::new()
+		};
+		let where_clause = input.parse()?;
+		let body = if input.peek(Token![;]) {
+			TypeDeclBody::Terminated(input.parse()?)
+		} else {
+			let content;
+			let brace = braced!(content in input);
+			let mut items = vec![];
+			while more_items(&content)? {
+				items.push(content.parse()?);
+			}
+			TypeDeclBody::Content((brace, items))
+		};
+		Ok(TypeDecl {
+			attrs,
+			ident,
+			generics: Generics {
+				where_clause,
+				..generics
+			},
+			bounds,
+			body,
+		})
+	}
+}
+
+impl Parse for AliasDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![type]>()?;
+		let ident: Ident = input.parse()?;
+		let generics: Generics = input.parse()?;
+		input.parse::<Token![=]>()?;
+		let ty = input.parse()?;
+		let where_clause = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(AliasDecl {
+			attrs,
+			ident,
+			generics: Generics {
+				where_clause,
+				..generics
+			},
+			ty,
+		})
+	}
+}
+
+impl Parse for AssocTypeDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![type]>()?;
+		let path: Path = input.parse()?;
+		parse_semi(input, path.span())?;
+		Ok(AssocTypeDecl {
+			attrs,
+			path,
+		})
+	}
+}
+
+impl Parse for FieldDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		// A field can also be called `field`, so it's only treated as the keyword if it's not followed by the colon.
+		if input.peek(kw::field) && !input.peek2(Token![:]) {
+			input.parse::<kw::field>()?;
+		}
+		let ident: Ident = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(FieldDecl {
+			attrs,
+			ident,
+			ty,
+		})
+	}
+}
+
+impl Parse for ConstructorDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		// Much like `field`, either of them can be the name of a field, which is told apart by what follows it.
+		let (span, fields) = if input.peek(kw::unit) {
+			(input.parse::<kw::unit>()?.span, None)
+		} else {
+			let span = input.parse::<kw::tuple>()?.span;
+			let content;
+			parenthesized!(content in input);
+			(span, Some(content.parse_terminated(Type::parse)?))
+		};
+		parse_semi(input, span)?;
+		Ok(ConstructorDecl {
+			attrs,
+			span,
+			fields,
+		})
+	}
+}
+
+impl Parse for OpDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<kw::op>()?;
+		let ident: Ident = input.parse()?;
+		let args = if input.peek(Token![<]) {
+			Some(input.parse()?)
+		} else {
+			None
+		};
+		let content;
+		parenthesized!(content in input);
+		let inputs = content.parse_terminated(FnArg::parse)?;
+		let output = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(OpDecl {
+			attrs,
+			ident,
+			args,
+			inputs,
+			output,
+		})
+	}
+}
+
+impl Parse for LayoutDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let mut entries = Punctuated::new();
+		loop {
+			entries.push_value(input.parse()?);
+			// A trailing comma is allowed, so it only continues if another entry follows it.
+			if !input.peek(Token![,]) {
+				break;
+			}
+			entries.push_punct(input.parse()?);
+			if !(input.peek(kw::size) || input.peek(kw::align)) || !input.peek2(Token![=]) {
+				break;
+			}
+		}
+		if input.peek(Token![;]) {
+			input.parse::<Token![;]>()?;
+		}
+		Ok(LayoutDecl {
+			attrs,
+			entries,
+		})
+	}
+}
+
+impl Parse for LayoutEntry {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let lookahead = input.lookahead1();
+		if !lookahead.peek(kw::size) && !lookahead.peek(kw::align) {
+			return Err(lookahead.error());
+		}
+		let key = input.parse()?;
+		input.parse::<Token![=]>()?;
+		let value = input.parse()?;
+		Ok(LayoutEntry {
+			key,
+			value,
+		})
+	}
+}
+
+impl Parse for TraitDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![trait]>()?;
+		let ident = input.parse()?;
+		let body = if input.peek(Token![;]) {
+			TraitDeclBody::Terminated(input.parse()?)
+		} else {
+			let content;
+			let brace = braced!(content in input);
+			let mut items = vec![];
+			while more_items(&content)? {
+				let ahead = content.fork();
+				ahead.call(Attribute::parse_outer)?;
+				if ahead.peek(Token![type]) {
+					items.push(TraitDeclItem::Type(content.parse()?));
+				} else {
+					items.push(TraitDeclItem::Method(content.call(parse_method)?));
+				}
+			}
+			TraitDeclBody::Content((brace, items))
+		};
+		Ok(TraitDecl {
+			attrs,
+			ident,
+			body,
+		})
+	}
+}
+
+impl Parse for EnumDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![enum]>()?;
+		let ident = input.parse()?;
+		let content;
+		braced!(content in input);
+		let variants = content.parse_terminated(Variant::parse)?;
+		Ok(EnumDecl {
+			attrs,
+			ident,
+			variants,
+		})
+	}
+}
+
+impl Parse for UnionDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let union_token = input.parse()?;
+		let ident = input.parse()?;
+		let fields = input.parse()?;
+		Ok(UnionDecl {
+			attrs,
+			union_token,
+			ident,
+			fields,
+		})
+	}
+}
+
+impl Parse for ConstDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![const]>()?;
+		let ident: Ident = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(ConstDecl {
+			attrs,
+			ident,
+			ty,
+		})
+	}
+}
+
+impl Parse for StaticDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![static]>()?;
+		let mutability = input.parse()?;
+		let ident: Ident = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let ty = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(StaticDecl {
+			attrs,
+			mutability,
+			ident,
+			ty,
+		})
+	}
+}
+
+impl Parse for SigDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<kw::sig>()?;
+		let ident: Ident = input.parse()?;
+		input.parse::<Token![=]>()?;
+		let ty = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(SigDecl {
+			attrs,
+			ident,
+			ty,
+		})
+	}
+}
+
+impl Parse for SigFnDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let fn_token = input.parse()?;
+		let ident: Ident = input.parse()?;
+		input.parse::<Token![:]>()?;
+		let sig = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(SigFnDecl {
+			attrs,
+			fn_token,
+			ident,
+			sig,
+		})
+	}
+}
+
+impl Parse for MacroDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![macro]>()?;
+		let ident: Ident = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(MacroDecl {
+			attrs,
+			ident,
+		})
+	}
+}
+
+impl Parse for ImplDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let impl_token = input.parse()?;
+		let mut generics: Generics = input.parse()?;
+		let trait_path: Path = input.parse()?;
+		input.parse::<Token![for]>()?;
+		let self_ty = input.parse()?;
+		generics.where_clause = input.parse()?;
+		let mut items = vec![];
+		if input.peek(token::Brace) {
+			let content;
+			braced!(content in input);
+			while more_items(&content)? {
+				items.push(content.call(parse_method)?);
+			}
+		} else {
+			parse_semi(input, trait_path.span())?;
+		}
+		Ok(ImplDecl {
+			attrs,
+			impl_token,
+			generics,
+			trait_path,
+			self_ty,
+			items,
+		})
+	}
+}
+
+impl Parse for ExternDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		let abi = input.parse()?;
+		let content;
+		braced!(content in input);
+		let mut items = vec![];
+		while more_items(&content)? {
+			let mut attrs = content.call(Attribute::parse_outer)?;
+			let vis = content.parse()?;
+			let mut method_item = content.call(parse_method)?;
+			attrs.append(&mut method_item.attrs);
+			method_item.attrs = attrs;
+			items.push((vis, method_item));
+		}
+		Ok(ExternDecl {
+			attrs,
+			abi,
+			items,
+		})
+	}
+}
+
+impl ExternDecl {
+	/// The functions as if they were declared on their own, with the block's ABI and attributes.
+	pub(crate) fn into_methods(self) -> Vec<(Visibility, TraitItemMethod)> {
+		let ExternDecl { attrs, abi, items } = self;
+		items.into_iter()
+			.map(|(vis, mut method_item)| {
+				if let Some(ref own_abi) = method_item.sig.abi {
+					own_abi.span()
+						.error("A function in an extern block can't declare its own ABI, as the block gives it one. [Hint: Remove the `extern`.]")
+						.emit();
+				}
+				// The ABI is moved onto the function, so that a mismatch points at it rather than the whole block.
+				let span = method_item.sig.decl.fn_token.span;
+				let mut name = abi.name.clone();
+				if let Some(ref mut name) = name {
+					name.set_span(span);
+				}
+				method_item.sig.abi = Some(Abi {
+					extern_token: Token![extern](span),
+					name,
+				});
+				// Much like a foreign function, it's unsafe to call, so it's checked as such.
+				if method_item.sig.unsafety.is_none() {
+					method_item.sig.unsafety = Some(Token![unsafe](span));
+				}
+				method_item.attrs = attrs.iter()
+					.cloned()
+					.chain(method_item.attrs)
+					.collect();
+				(vis, method_item)
+			})
+			.collect()
+	}
+}
+
+impl Parse for UseDecl {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let attrs = input.call(Attribute::parse_outer)?;
+		input.parse::<Token![use]>()?;
+		let fn_token = input.parse()?;
+		let ident: Ident = input.parse()?;
+		parse_semi(input, ident.span())?;
+		Ok(UseDecl {
+			attrs,
+			fn_token,
+			ident,
+		})
+	}
+}
@@ -0,0 +1,4054 @@
+/*!
+The parsing and generation behind `def_mod!`, for any other proc macro that wants to generate the same checks.
+
+The invocation as a whole is expanded by [`expand`], which is exactly what the macros call.
+The declarations themselves are in [`ast`], where a single module can be parsed on its own, or put together from items, and turned into the same tokens:
+
+```rust,ignore
+let mut module = ModuleDecl::new(Visibility::Inherited, parse_quote!(my_mod));
+module.push_item(Visibility::Inherited, parse_quote!(fn method(_: u32) -> u8;));
+let tokens = module.into_token_stream();
+```
+
+A method can also be checked on its own with [`convert`], against a module that's declared some other way.
+
+[`render_tokens`] lays the tokens out as source, which is what `def_mod_debug!` prints.
+With the `testing` feature, `render` does the same for the checks that the modules expand to, which can be compared against a file that's kept alongside the tests.
+It's what the modules generate that's rendered, and not the declarations themselves, so it's the checks, along with any diagnostics, that a snapshot pins down.
+
+Outside of a proc macro, such as in a test, the diagnostics are added to the tokens, the same as they are with the `stable` feature, and the paths aren't checked, as there's no file to check them against.
+*/
+
+#![cfg_attr(not(feature = "stable"), feature(proc_macro_diagnostic))]
+#![cfg_attr(not(feature = "stable"), feature(proc_macro_tracked_env))]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro2::{TokenStream, TokenTree, Group, Span};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::*;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::parse::{Lookahead1, Parse, ParseStream, Parser};
+use syn::visit_mut::VisitMut;
+use diagnostic::Diagnose;
+
+pub mod ast;
+pub use ast::*;
+
+/// Generates the declarations, if asked to, along with the checks for each of the modules.
+/// The tokens are the whole body of an invocation, and without `declare`, the modules are only checked, like `def_mod_check!`.
+/// They're also only checked with `#![verify_only]`, or `DEF_MOD_VERIFY_ONLY=1`, whichever macro it is.
+pub fn expand(tokens: TokenStream, declare: bool) -> TokenStream {
+	let (mut attrs, declarations) = match parse_invocation.parse2(tokens) {
+		Ok(invocation) => invocation,
+		Err(error) => {
+			let mut output = error.to_compile_error();
+			output.extend(diagnostic::take());
+			return output;
+		}
+	};
+
+	let manifest = take_attr(&mut attrs, "manifest")
+		.and_then(|attr| manifest_path(&attr));
+	// With `DEF_MOD_VERIFY_ONLY=1`, every invocation in the crate is only checked, as if each of them had the attribute.
+	let verify_only = take_attr(&mut attrs, "verify_only").is_some()
+		|| env_var("DEF_MOD_VERIFY_ONLY").is_ok_and(|value| value == "1");
+	for attr in attrs {
+		attr.span()
+			.error("Only #![manifest] and #![verify_only] can be given to the invocation itself.")
+			.emit();
+	}
+	let mode = if verify_only {
+		Mode::VerifyOnly
+	} else if declare {
+		Mode::Declare
+	} else {
+		Mode::Check
+	};
+	// The variable is only a fallback, so it's ignored when the feature isn't there to use it.
+	#[cfg(feature = "manifest")]
+	let manifest = manifest.or_else(|| {
+		env_var("DEF_MOD_MANIFEST")
+			.ok()
+			.map(|path| LitStr::new(&path, Span::call_site()))
+	});
+	#[cfg(feature = "manifest")]
+	{
+		if let Some(ref path) = manifest {
+			manifest::write(path, &declarations);
+		}
+	}
+	#[cfg(not(feature = "manifest"))]
+	{
+		if let Some(ref path) = manifest {
+			path.span()
+				.warning("The manifest isn't written, as the `manifest` feature isn't enabled. [Hint: Enable it in the def-mod dependency.]")
+				.emit();
+		}
+	}
+
+	// With `DEF_MOD_TRACE=1`, each module reports how many checks it declared, and how long they took to generate, so a slow invocation can be found.
+	let trace = env_var("DEF_MOD_TRACE")
+		.is_ok_and(|value| value == "1");
+
+	let mut output = TokenStream::new();
+	// The modules that were already declared, along with the cfgs that gate them.
+	let mut declared: Vec<(Ident, Option<String>)> = vec![];
+	for module in declarations {
+		if !trace {
+			tokenise_declaration(module, mode, &mut declared).to_tokens(&mut output);
+			continue;
+		}
+		// Outside of a proc macro, there's no file to point at.
+		let location = if proc_macro::is_available() {
+			let span = module.ident.span().unstable();
+			format!("{}:{}", span.file(), span.line())
+		} else {
+			"<unknown>".to_owned()
+		};
+		let name = module.ident.to_string();
+		let checks = match module.body {
+			ModuleBody::Content((_brace, ref body)) => count_checks(body),
+			ModuleBody::Terminated(_) => 0,
+		};
+		let start = std::time::Instant::now();
+		tokenise_declaration(module, mode, &mut declared).to_tokens(&mut output);
+		eprintln!("def_mod: {} mod {}, checks: {}, took {:?}", location, name, checks, start.elapsed());
+	}
+	output.extend(diagnostic::take());
+	output
+}
+
+/// Whether the modules of an invocation are declared, or only checked.
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+	/// `def_mod!`
+	Declare,
+	/// `def_mod_check!`, where a path is an error, as there's nothing to give it to.
+	Check,
+	/// `#![verify_only]`, where the modules are declared by hand, so their paths are left out, but still gate the checks.
+	VerifyOnly,
+}
+
+/// Counts the checks that a module's body declares, which includes the items inside of its types, traits and submodules.
+fn count_checks(body: &[(Visibility, DeclItem)]) -> usize {
+	body.iter()
+		.map(|(_vis, item)| match item {
+			DeclItem::Type(TypeDecl { body: TypeDeclBody::Content((_brace, items)), .. }) => 1 + items.len(),
+			DeclItem::Trait(TraitDecl { body: TraitDeclBody::Content((_brace, items)), .. }) => 1 + items.len(),
+			DeclItem::Extern(extern_item) => extern_item.items.len(),
+			DeclItem::Impl(impl_item) => 1 + impl_item.items.len(),
+			DeclItem::Sig(_) => 0,
+			DeclItem::Module(ModuleDecl { body: ModuleBody::Content((_brace, body)), .. }) => count_checks(body),
+			_ => 1,
+		})
+		.sum()
+}
+
+/// Generates the declaration of a single top-level module, if asked to, along with its load function.
+fn tokenise_declaration(module: ModuleDecl, mode: Mode, declared: &mut Vec<(Ident, Option<String>)>) -> TokenStream {
+	let mut output = TokenStream::new();
+	let (pathed_attrs, mut custom_attrs) = group_attrs(module.attrs);
+	let as_trait = take_attr(&mut custom_attrs, "as_trait")
+		.and_then(|attr| as_trait_name(&attr));
+	let sealed = take_attr(&mut custom_attrs, "sealed")
+		.and_then(|attr| sealed_names(&attr));
+	let env_path = take_attr(&mut custom_attrs, "path_env")
+		.and_then(|attr| path_env_var(&attr))
+		.and_then(|var| env_path(&var));
+	let root = take_attr(&mut custom_attrs, "root")
+		.and_then(|attr| root_path(&attr));
+	// Without a root of its own, a module that's verified is left to its declaration by hand, which is under `self`.
+	let verify_only = mode == Mode::VerifyOnly && root.is_none();
+	let root = if mode == Mode::Declare || root.is_some() {
+		root
+	} else if verify_only {
+		Some(parse_quote!(self))
+	} else {
+		let path = pathed_attrs.first()
+			.map(|(_attr, path)| path)
+			.or(env_path.as_ref());
+		if let Some(path) = path {
+			path.span()
+				.error("A module that's only checked isn't declared, so there's nothing to give the path to. [Hint: Use the attribute on its own, such as `#[cfg(unix)]`.]")
+				.emit();
+			return output;
+		}
+		Some(parse_quote!(self))
+	};
+	let load_fn = take_attr(&mut custom_attrs, "load_fn")
+		.and_then(|attr| load_fn_name(&attr));
+	let no_glob = take_attr(&mut custom_attrs, "no_glob").is_some();
+	let assert_style = take_attr(&mut custom_attrs, "assert_style")
+		.and_then(|attr| assert_style(&attr));
+	let no_std = take_attr(&mut custom_attrs, "no_std")
+		.and_then(|attr| no_std_types(&attr));
+	let prefix = take_attr(&mut custom_attrs, "prefix")
+		.and_then(|attr| prefix_name(&attr));
+	let object_safe = take_attr(&mut custom_attrs, "object_safe")
+		.and_then(|attr| object_safe_name(&attr));
+	let expect_cfg = take_attr(&mut custom_attrs, "expect_cfg")
+		.and_then(|attr| expect_cfg_predicates(&attr).map(|predicates| (attr, predicates)));
+	// Ghost the attr vectors, so no one can change them...
+	let pathed_attrs = &pathed_attrs;
+	let custom_attrs = &custom_attrs;
+
+	let vis = &module.vis;
+	let mod_token = &module.mod_token;
+	let module_name = &module.ident;
+	let mod_attrs = &module.mod_attrs;
+	let load_attrs = &module.load_attrs;
+
+	// Declaring a module more than once is only fine if the declarations are gated differently.
+	let gate = module_gate(pathed_attrs, custom_attrs);
+	let conflict = declared.iter()
+		.any(|(ident, other)| ident == module_name && (gate.is_none() || other.is_none() || gate == *other));
+	if conflict {
+		module_name.span()
+			.error(format!("The module `{}` has already been declared. [Hint: Declarations of the same module have to be gated by different #[cfg] attributes.]", module_name))
+			.emit();
+		return output;
+	}
+	declared.push((module_name.clone(), gate));
+
+	if verify_only {
+		// Neither the paths nor the attributes are needed, as they're already on the declaration by hand.
+	} else if let Some(ref root) = root {
+		// The module is declared somewhere else, so there's nothing to declare here, just the checks to generate.
+		if !pathed_attrs.is_empty() || env_path.is_some() {
+			root.span()
+				.error("A #[root] can't be used along with a path, as the module is already declared under the root.")
+				.emit();
+		}
+		if let Some(attr) = mod_attrs.first() {
+			attr.span()
+				.warning("The module is already declared under the root, so there's no declaration for the attribute to go on.")
+				.emit();
+		}
+	} else if let Some(path) = env_path {
+		if pathed_attrs.is_empty() {
+			let t = quote_spanned! { module_name.span() =>
+				#[path=#path]
+				#(#custom_attrs)*
+				#(#mod_attrs)*
+				#vis #mod_token #module_name;
+			};
+			t.to_tokens(&mut output);
+		} else {
+			path.span()
+				.error("A #[path_env] can't be used along with pathed attributes. [Hint: Use `= env!(\"...\")` on the attributes instead.]")
+				.emit();
+		}
+	} else if pathed_attrs.is_empty() {
+		let t = quote_spanned! { module_name.span() =>
+			#(#custom_attrs)*
+			#(#mod_attrs)*
+			#vis #mod_token #module_name;
+		};
+		t.to_tokens(&mut output);
+	} else {
+		// If there were some pathed attrs, then we need to generate an equal number of mod decls with the attrs.
+		for (attr, path) in pathed_attrs {
+			if !path_exists(path) {
+				// The attribute is usually a cfg, which can't be evaluated here, so the error is gated by it, just like the declaration would be.
+				let message = format!("The path \"{}\" doesn't exist. [Hint: It's relative to the directory of the file that invokes the macro.]", path.value());
+				let t = quote_spanned! { path.span() =>
+					#attr
+					compile_error!(#message);
+				};
+				t.to_tokens(&mut output);
+				continue;
+			}
+			let t = quote_spanned! { module_name.span() =>
+				#attr
+				#[path=#path]
+				#(#custom_attrs)*
+				#(#mod_attrs)*
+				#vis #mod_token #module_name;
+			};
+			t.to_tokens(&mut output);
+		}
+		tokenise_overlaps(module_name, pathed_attrs).to_tokens(&mut output);
+	}
+
+	// The checks only make sense when the module is there, so they're gated the same way.
+	// None of the pathed attrs might be active, in which case there's no module for them to refer to.
+	let cfg_attrs = custom_attrs.iter()
+		.filter(|attr| is_attr(attr, "cfg"));
+	let pathed_cfg = pathed_cfg(pathed_attrs);
+	let gate = quote! {
+		#pathed_cfg
+		#(#cfg_attrs)*
+	};
+
+	// The module's own attributes can't be read, but whether its checks are generated under the expected cfgs can be.
+	if let Some((attr, predicates)) = expect_cfg {
+		let predicate = quote!(all(#(#predicates),*));
+		let message = format!("The module `{}` is expected to be checked with `cfg({})`, which isn't active. [Hint: Its checks are left out otherwise, so enable it, or remove it from #[expect_cfg].]", module_name, predicate);
+		let t = quote_spanned! { attr.span() =>
+			#gate
+			#[cfg(not(#predicate))]
+			compile_error!(#message);
+		};
+		t.to_tokens(&mut output);
+	}
+
+	// Generate a load function, if the module was declared with some items.
+	if let ModuleBody::Content((_brace, body)) = module.body {
+		let body = resolve_sigs(body, &[]);
+		// A module that's declared elsewhere is only known to exist by the import in its load function.
+		let imported = root.is_some();
+		let mut module_path = ModulePath::new(root, module_name);
+		module_path.glob = !no_glob;
+		if let Some(assert_style) = assert_style {
+			module_path.assert_style = assert_style;
+		}
+		if let Some(no_std) = no_std {
+			module_path.no_std = no_std;
+		}
+		if let Some(prefix) = prefix {
+			module_path.prefix = prefix;
+		}
+		if let Some(ref trait_name) = as_trait {
+			tokenise_as_trait(&module_path, &gate, vis, trait_name, &body).to_tokens(&mut output);
+		}
+		tokenise_smoke_tests(&module_path, &gate, &body)
+			.into_iter()
+			.for_each(|t| t.to_tokens(&mut output));
+
+		let mut index: u32 = 0;
+		let (object_check, body) = match object_safe {
+			Some(ref trait_name) => tokenise_object_safe(&module_path, &mut index, trait_name, body),
+			None => (None, body),
+		};
+		let items = tokenise_items(&module_path, &mut index, body);
+		let sealed_check = sealed.map(|names| tokenise_sealed(&module_path, &names));
+		// An empty body is the same as not having one, unless there's something else for the load function to do, or it's called by name.
+		if items.is_empty() && object_check.is_none() && sealed_check.is_none() && load_fn.is_none() && !imported {
+			return output;
+		}
+
+		let function_name = load_fn.unwrap_or_else(|| {
+			let name = format!("{}load_{}", module_path.prefix, module_path.flat_name());
+			Ident::new(&name, module_name.span())
+		});
+		let glob = if module_path.glob {
+			Some(quote!(use #module_path::*;))
+		} else {
+			None
+		};
+		// Naming a deprecated item isn't a use of it, so it shouldn't warn.
+		// A method's `where` clause is checked alongside its type's bounds, so they're split on purpose.
+		// The names are put together from the #[prefix], which can be in any case.
+		let t = quote! {
+			#gate
+			#(#load_attrs)*
+			#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+			fn #function_name() {
+				#glob
+				#(#items)*
+				#object_check
+				#sealed_check
+			}
+		};
+		t.to_tokens(&mut output);
+	} else {
+		if let Some(trait_name) = as_trait {
+			trait_name.span()
+				.warning("The module doesn't declare a body, so there's nothing to generate the trait from.")
+				.emit();
+		}
+		if let Some(names) = sealed {
+			if let Some(name) = names.first() {
+				name.span()
+					.warning("The module doesn't declare a body, so it isn't checked. [Hint: Use `{}` as the body instead.]")
+					.emit();
+			}
+		}
+		if let Some(load_fn) = load_fn {
+			load_fn.span()
+				.warning("The module doesn't declare a body, so there's no load function to name. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if let Some(attr) = load_attrs.first() {
+			attr.span()
+				.warning("The module doesn't declare a body, so there's no load function for the attribute to go on. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if let Some(root) = root.filter(|_| !verify_only) {
+			root.span()
+				.warning("The module doesn't declare a body, so there's nothing to check under the root. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if no_glob {
+			module_name.span()
+				.warning("The module doesn't declare a body, so there's no load function to leave the import out of. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if assert_style.is_some() {
+			module_name.span()
+				.warning("The module doesn't declare a body, so there's nothing to assert. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if no_std.is_some() {
+			module_name.span()
+				.warning("The module doesn't declare a body, so there are no signatures to look through. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if prefix.is_some() {
+			module_name.span()
+				.warning("The module doesn't declare a body, so there's nothing to name with the prefix. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+		if let Some(trait_name) = object_safe {
+			trait_name.span()
+				.warning("The module doesn't declare a body, so there are no methods to call on the trait object. [Hint: Use `{}` as the body instead.]")
+				.emit();
+		}
+	}
+	output
+}
+
+/// The cfgs that gate a module declaration, as a string so they can be compared.
+/// A declaration that isn't gated is always there, so nothing is returned.
+fn module_gate(pathed_attrs: &[(Attribute, LitStr)], custom_attrs: &[Attribute]) -> Option<String> {
+	let gate = pathed_attrs.iter()
+		.map(|(attr, _path)| attr)
+		.chain(custom_attrs.iter().filter(|attr| is_attr(attr, "cfg")))
+		.map(|attr| attr.into_token_stream().to_string())
+		.collect::<Vec<_>>();
+	if gate.is_empty() {
+		None
+	} else {
+		Some(gate.join(" "))
+	}
+}
+
+/// A pathed module only exists if one of its attributes is active, so the checks are gated by any of them.
+/// That only works if all of them are a cfg, otherwise there's nothing to gate by.
+fn pathed_cfg(pathed_attrs: &[(Attribute, LitStr)]) -> Option<TokenStream> {
+	if pathed_attrs.is_empty() {
+		return None;
+	}
+	let predicates = pathed_attrs.iter()
+		.map(|(attr, _path)| cfg_predicate(attr))
+		.collect::<Option<Vec<_>>>()?;
+	Some(quote!(#[cfg(any(#(#predicates),*))]))
+}
+
+/// The pathed attributes are mutually exclusive, but they're usually cfgs, which can't be evaluated here.
+/// So an error is generated for each pair of them, which is gated by both, as the module would be declared twice.
+fn tokenise_overlaps(module_name: &Ident, pathed_attrs: &[(Attribute, LitStr)]) -> TokenStream {
+	let mut output = TokenStream::new();
+	let predicates = pathed_attrs.iter()
+		.map(|(attr, path)| cfg_predicate(attr).map(|predicate| (predicate, path)))
+		.collect::<Option<Vec<_>>>()
+		.unwrap_or_default();
+	for (i, (predicate, path)) in predicates.iter().enumerate() {
+		for (other_predicate, other_path) in &predicates[..i] {
+			let message = format!("The paths \"{}\" and \"{}\" are both active, so the module `{}` would be declared twice. [Hint: The attributes of a pathed module are mutually exclusive, so their cfgs can't overlap.]", other_path.value(), path.value(), module_name);
+			let t = quote_spanned! { path.span() =>
+				#[cfg(all(#other_predicate, #predicate))]
+				compile_error!(#message);
+			};
+			t.to_tokens(&mut output);
+		}
+	}
+	output
+}
+
+/// Gets the predicate out of a cfg attribute: `#[cfg(unix)]`
+fn cfg_predicate(attr: &Attribute) -> Option<NestedMeta> {
+	if !is_attr(attr, "cfg") {
+		return None;
+	}
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		if nested.len() == 1 {
+			return nested.into_iter().next();
+		}
+	}
+	None
+}
+
+/// Splits the attributes into the ones that were declared with a path, and the ones that weren't.
+fn group_attrs(attrs: Vec<(Attribute, Option<PathValue>)>) -> (Vec<(Attribute, LitStr)>, Vec<Attribute>) {
+	let mut pathed_attrs = vec![];
+	let mut custom_attrs = vec![];
+	for attr in attrs {
+		if let (attr, Some(PathValue::Lit(path))) = attr {
+			if let Some(path) = resolve_path_alias(&attr, path, &pathed_attrs) {
+				pathed_attrs.push((attr, path));
+			}
+		} else if let (attr, Some(PathValue::Env(var))) = attr {
+			// The variable could hold anything, so it's never treated as an alias.
+			if let Some(path) = env_path(&var) {
+				pathed_attrs.push((attr, path));
+			}
+		} else if let (attr, Some(PathValue::Candidates(candidates))) = attr {
+			if let Some(path) = existing_path(&candidates) {
+				pathed_attrs.push((attr, path));
+			}
+		} else if let Some((attr, path)) = platform_shorthand(&attr.0) {
+			if let Some(path) = resolve_path_alias(&attr, path, &pathed_attrs) {
+				pathed_attrs.push((attr, path));
+			}
+		} else {
+			custom_attrs.push(attr.0);
+		};
+	}
+	(pathed_attrs, custom_attrs)
+}
+
+/// Replaces each function that's declared with a named signature by the method that it describes, throughout the module and its submodules.
+/// The signatures don't generate anything on their own, so they're taken out, once they've been added to the ones from the parents.
+fn resolve_sigs(body: Vec<(Visibility, DeclItem)>, parent_sigs: &[SigDecl]) -> Vec<(Visibility, DeclItem)> {
+	let (sig_items, rest): (Vec<_>, Vec<_>) = body.into_iter()
+		.partition(|(_vis, item)| matches!(item, DeclItem::Sig(_)));
+	// A module's own signatures come first, so they shadow the ones of its parents.
+	let sigs: Vec<SigDecl> = sig_items.into_iter()
+		.filter_map(|(_vis, item)| match item {
+			DeclItem::Sig(sig_item) => Some(sig_item),
+			_ => None,
+		})
+		.chain(parent_sigs.iter().cloned())
+		.collect();
+	rest.into_iter()
+		.filter_map(|(vis, item)| match item {
+			DeclItem::SigFn(sig_fn_item) => {
+				match sigs.iter().find(|sig_item| sig_item.ident == sig_fn_item.sig) {
+					Some(sig_item) => Some((vis, DeclItem::Method(sig_method(sig_fn_item, &sig_item.ty)))),
+					None => {
+						sig_fn_item.sig.span()
+							.error(format!("The signature `{}` isn't declared in this module, or in any of its parents. [Hint: Declare it with `sig {} = fn(...);`.]", sig_fn_item.sig, sig_fn_item.sig))
+							.emit();
+						None
+					}
+				}
+			}
+			DeclItem::Module(mut module_item) => {
+				if let ModuleBody::Content((brace, body)) = module_item.body {
+					module_item.body = ModuleBody::Content((brace, resolve_sigs(body, &sigs)));
+				}
+				Some((vis, DeclItem::Module(module_item)))
+			}
+			item => Some((vis, item)),
+		})
+		.collect()
+}
+
+/// The method that a named signature describes, with each of its parameters as `_: T`, and its lifetimes, `for<'a>`, as the method's own.
+fn sig_method(sig_fn_item: SigFnDecl, ty: &TypeBareFn) -> TraitItemMethod {
+	let SigFnDecl {
+		attrs,
+		fn_token,
+		ident,
+		sig,
+	} = sig_fn_item;
+	// The signature is spanned to where it's named, so an error points at the function, and not at the declaration that it shares with the others.
+	let ty: TypeBareFn = parse2(respan(ty.clone().into_token_stream(), sig.span())).expect("Should never happen [sig]");
+	let inputs = ty.inputs.iter()
+		.map(|arg| -> FnArg {
+			let ty = &arg.ty;
+			parse_quote!(_: #ty)
+		})
+		.collect();
+	let generics = Generics {
+		lt_token: ty.lifetimes.as_ref().map(|bound| bound.lt_token),
+		params: ty.lifetimes.iter()
+			.flat_map(|bound| bound.lifetimes.iter().cloned())
+			.map(GenericParam::Lifetime)
+			.collect(),
+		gt_token: ty.lifetimes.as_ref().map(|bound| bound.gt_token),
+		where_clause: None,
+	};
+	TraitItemMethod {
+		attrs,
+		sig: MethodSig {
+			constness: None,
+			asyncness: None,
+			unsafety: ty.unsafety,
+			abi: ty.abi.clone(),
+			ident,
+			decl: FnDecl {
+				fn_token,
+				generics,
+				paren_token: ty.paren_token,
+				inputs,
+				variadic: ty.variadic,
+				output: ty.output.clone(),
+			},
+		},
+		default: None,
+		semi_token: Some(Token![;](sig.span())),
+	}
+}
+
+/// Transforms each item of a module's body into the corresponding check.
+fn tokenise_items(module_path: &ModulePath, index: &mut u32, body: Vec<(Visibility, DeclItem)>) -> Vec<TokenStream> {
+	check_aliases(&body);
+	check_overloads(&body);
+	body.into_iter()
+		.map(|(vis, mut item)| {
+			lint_no_std(module_path, &item.signatures());
+			match item {
+				// A module's exports are only glob imported into its own checks, an implementation doesn't have a name,
+				// and a function of a type is named through it.
+				DeclItem::Module(_) | DeclItem::Impl(_) | DeclItem::AssocFn(_) => {}
+				DeclItem::Extern(ref extern_item) => {
+					for (_vis, method_item) in extern_item.items.iter() {
+						lint_prelude(module_path, &method_item.sig.ident);
+					}
+				}
+				ref item => lint_prelude(module_path, item.ident()),
+			}
+			item.resolve_super(module_path);
+			let vis_check = match item {
+				// A module can be gated by its pathed attrs, so it has to check its own visibility.
+				// An extern block doesn't have any, but each of its functions does.
+				DeclItem::Module(_) | DeclItem::Extern(_) => TokenStream::new(),
+				// An implementation is as visible as the trait and the type, and a function of a type doesn't have one.
+				DeclItem::Impl(_) | DeclItem::AssocFn(_) => TokenStream::new(),
+				ref item => tokenise_visibility(module_path, &vis, item.ident(), item.attrs()),
+			};
+			let check = match item {
+				DeclItem::Method(mut method_item) => {
+					let const_check = take_attr(&mut method_item.attrs, "as_const")
+						.and_then(|attr| as_const_name(&attr))
+						.map(|const_name| tokenise_as_const(module_path, index, &method_item, &const_name));
+					let check = tokenise_method(module_path, None, index, method_item);
+					quote! {
+						#check
+						#const_check
+					}
+				}
+				DeclItem::AssocFn(assoc_item) => tokenise_assoc_fn(module_path, index, assoc_item),
+				DeclItem::Sig(_) | DeclItem::SigFn(_) => unreachable!("The signatures are resolved before the items are checked."),
+				DeclItem::Type(type_item) => tokenise_type(module_path, index, type_item),
+				DeclItem::Trait(trait_item) => tokenise_trait(module_path, index, trait_item),
+				DeclItem::Enum(enum_item) => tokenise_enum(module_path, enum_item),
+				DeclItem::Union(union_item) => tokenise_union(module_path, union_item),
+				DeclItem::Alias(alias_item) => tokenise_alias(module_path, index, alias_item),
+				DeclItem::Impl(impl_item) => tokenise_impl(module_path, index, impl_item),
+				DeclItem::Module(module_item) => tokenise_module(module_path, index, module_item),
+				DeclItem::Extern(extern_item) => extern_item.into_methods()
+					.into_iter()
+					.map(|(vis, method_item)| {
+						let vis_check = tokenise_visibility(module_path, &vis, &method_item.sig.ident, &method_item.attrs);
+						let check = tokenise_method(module_path, None, index, method_item);
+						quote! {
+							#vis_check
+							#check
+						}
+					})
+					.collect(),
+				DeclItem::Const(const_item) => {
+					let attrs = &check_attrs(&const_item.attrs);
+					let ident = &const_item.ident;
+					let ty = &const_item.ty;
+					let load_ident = {
+						let name = format!("{}ASSERT_CONST_{}", module_path.prefix, index);
+						Ident::new(&name, ident.span())
+					};
+					*index += 1;
+					// The value is spanned to the name, so a mismatch, like a stored pointer with another signature, is reported there.
+					let value = respan(quote!(#module_path::#ident), ident.span());
+					quote_spanned! { ident.span() =>
+						#(#attrs)*
+						const #load_ident: #ty = #value;
+					}
+				}
+				DeclItem::Static(static_item) => {
+					let attrs = &check_attrs(&static_item.attrs);
+					let ident = &static_item.ident;
+					let ty = &static_item.ty;
+					// The path is spanned to the name, so a static that's declared the other way is reported there.
+					let value = respan(quote!(#module_path::#ident), ident.span());
+					if static_item.mutability.is_some() {
+						// Only a mutable static can be taken as mutable, which doesn't need an unsafe block, as nothing is read or written.
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							let _: *mut #ty = ::core::ptr::addr_of_mut!(#value);
+						}
+					} else {
+						// Taking a reference means we only care about the type, and not how the static was declared.
+						// A mutable static fails, as it can't be referenced outside of an unsafe block.
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							let _: &#ty = &#value;
+						}
+					}
+				}
+				DeclItem::Use(use_item) => {
+					let attrs = &check_attrs(&use_item.attrs);
+					let ident = &use_item.ident;
+					if use_item.fn_token.is_some() {
+						// Referencing the value makes sure it's one, unlike the import.
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							let _ = #module_path::#ident;
+						}
+					} else {
+						let import = tokenise_import(module_path, ident);
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							{
+								#import
+							}
+						}
+					}
+				}
+				DeclItem::Macro(macro_item) => {
+					let attrs = &check_attrs(&macro_item.attrs);
+					let ident = &macro_item.ident;
+					// There's no way to name a macro without calling it, so the import is the best that can be done.
+					// It only checks the name, so anything else that's called the same will pass too.
+					let import = tokenise_import(module_path, ident);
+					quote_spanned! { ident.span() =>
+						#(#attrs)*
+						{
+							#import
+						}
+					}
+				}
+			};
+			quote! {
+				#vis_check
+				#check
+			}
+		})
+		.filter(|t| !t.is_empty())
+		.collect()
+}
+
+/// Makes sure a name isn't declared as both a type and an alias, as they'd check for different things.
+/// Much like a module, it's fine if the declarations are gated differently, as only one of them is there.
+fn check_aliases(body: &[(Visibility, DeclItem)]) {
+	let types: Vec<(&Ident, Option<String>)> = body.iter()
+		.filter_map(|(_vis, item)| match item {
+			DeclItem::Type(type_item) => Some((&type_item.ident, module_gate(&[], &type_item.attrs))),
+			_ => None,
+		})
+		.collect();
+	for (_vis, item) in body {
+		if let DeclItem::Alias(alias_item) = item {
+			let gate = module_gate(&[], &alias_item.attrs);
+			let conflict = types.iter()
+				.any(|(ident, other)| **ident == alias_item.ident && (gate.is_none() || other.is_none() || gate == *other));
+			if conflict {
+				alias_item.ident.span()
+					.error(format!("The type `{}` is declared as both a type and an alias. [Hint: Keep the alias, as it also checks that the type exists.]", alias_item.ident))
+					.emit();
+			}
+		}
+	}
+}
+
+/// Reports a function that's declared more than once, which only works if each of them is gated by its own cfgs, like a signature that differs between targets.
+/// Otherwise, it looks like an overload, which Rust doesn't have, so all but one of the checks would fail against the same function.
+fn check_overloads(body: &[(Visibility, DeclItem)]) {
+	let mut functions: Vec<(&Ident, Option<String>)> = vec![];
+	for (_vis, item) in body {
+		let declared: Vec<(&Ident, Option<String>)> = match item {
+			DeclItem::Method(method_item) => vec![(&method_item.sig.ident, module_gate(&[], &method_item.attrs))],
+			DeclItem::Extern(extern_item) => extern_item.items.iter()
+				.map(|(_vis, method_item)| {
+					let attrs: Vec<Attribute> = extern_item.attrs.iter()
+						.chain(method_item.attrs.iter())
+						.cloned()
+						.collect();
+					(&method_item.sig.ident, module_gate(&[], &attrs))
+				})
+				.collect(),
+			_ => continue,
+		};
+		for (ident, gate) in declared {
+			let conflict = functions.iter()
+				.any(|(other, other_gate)| **other == *ident && (gate.is_none() || other_gate.is_none() || gate == *other_gate));
+			if conflict {
+				ident.span()
+					.error(format!("The function `{}` is declared more than once, but Rust doesn't have overloading, so only one of the signatures could ever match. [Hint: Remove all but one, or gate each of them with a different #[cfg].]", ident))
+					.emit();
+			}
+			functions.push((ident, gate));
+		}
+	}
+}
+
+/// Generates the checks for a single method, as if it were declared in the body of the module at the path: `self::my_mod`
+/// They're statements, so they have to go inside of a function, and each method in the same function needs an index of its own.
+pub fn convert(module: &Path, index: u32, method_item: TraitItemMethod) -> TokenStream {
+	let mut segments: Vec<Ident> = module.segments.iter()
+		.map(|segment| segment.ident.clone())
+		.collect();
+	let ident = match segments.pop() {
+		Some(ident) => ident,
+		None => return TokenStream::new(),
+	};
+	let root = if segments.is_empty() {
+		None
+	} else {
+		Some(Path {
+			leading_colon: module.leading_colon,
+			segments: segments.into_iter().map(PathSegment::from).collect(),
+		})
+	};
+	let module_path = ModulePath::new(root, &ident);
+	let mut output = convert_method(&module_path, None, index, method_item);
+	output.extend(diagnostic::take());
+	output
+}
+
+/// Generates the same tokens as `def_mod!` would for the module on its own.
+impl ToTokens for ModuleDecl {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		tokenise_declaration(self.clone(), Mode::Declare, &mut vec![]).to_tokens(tokens);
+		diagnostic::take().to_tokens(tokens);
+	}
+}
+
+/// Renders the modules as source, with an item to a line, so they can be compared against a file in a test.
+/// It's not the declarations that are rendered, but the checks that they expand to, the same as `to_token_stream()` gives,
+/// which includes the diagnostics that weren't emitted, such as outside of a proc macro.
+/// Nothing is resolved, so it's the same as the tokens, just laid out to be read.
+#[cfg(feature = "testing")]
+pub fn render(decls: &[ModuleDecl]) -> String {
+	decls.iter()
+		.map(|decl| render_tokens(decl.into_token_stream()))
+		.collect()
+}
+
+/// Lays the tokens out as source, with an item to a line, which is how `def_mod_debug!` prints what it generated.
+///
+/// ```
+/// let tokens = quote::quote!(fn _load_my_mod() { const _ASSERT_METHOD_0: fn() = self::my_mod::method; });
+/// let rendered = def_mod_core::render_tokens(tokens);
+/// assert_eq!(rendered, "fn _load_my_mod() {\n\tconst _ASSERT_METHOD_0: fn() = self::my_mod::method;\n}\n");
+/// ```
+pub fn render_tokens(tokens: TokenStream) -> String {
+	let mut output = String::new();
+	write_tokens(tokens, 0, &mut output);
+	output
+}
+
+/// Writes the tokens out, where every `;` and attribute ends a line, and a brace indents what's inside of it, unless it fits on the line.
+fn write_tokens(tokens: TokenStream, depth: usize, output: &mut String) {
+	use proc_macro2::{Delimiter, Spacing};
+
+	let mut line = String::new();
+	// Whether the next token is written straight after the last one, such as after `::` or `#`.
+	let mut joined = true;
+	// Whether the last token was a punct that's joined to the next one, which tells the second `:` of a `::` from a `:` on its own.
+	let mut last_joint = false;
+	let flush = |line: &mut String, output: &mut String| {
+		if !line.is_empty() {
+			output.push_str(&"\t".repeat(depth));
+			output.push_str(line.trim_end());
+			output.push('\n');
+			line.clear();
+		}
+	};
+	let mut tokens = tokens.into_iter().peekable();
+	while let Some(tt) = tokens.next() {
+		let after_joint = last_joint;
+		last_joint = matches!(tt, TokenTree::Punct(ref punct) if punct.spacing() == Spacing::Joint);
+		match tt {
+			TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+				let mut inner = String::new();
+				write_tokens(group.stream(), depth + 1, &mut inner);
+				if !joined && !line.is_empty() {
+					line.push(' ');
+				}
+				// Anything without a statement in it, like `{}` or an import's `{MyStruct, Other}`, stays on the line.
+				if inner.lines().count() <= 1 && !inner.trim_end().ends_with(';') {
+					line.push('{');
+					line.push_str(inner.trim());
+					line.push('}');
+					joined = false;
+					// It's the end of the item, unless something like a `;` or `,` follows.
+					let ends_item = match tokens.peek() {
+						Some(TokenTree::Ident(_)) => true,
+						Some(TokenTree::Punct(punct)) => punct.as_char() == '#',
+						_ => false,
+					};
+					if ends_item {
+						flush(&mut line, output);
+						joined = true;
+					}
+					continue;
+				}
+				line.push('{');
+				flush(&mut line, output);
+				output.push_str(&inner);
+				output.push_str(&"\t".repeat(depth));
+				output.push_str("}\n");
+				joined = true;
+			}
+			TokenTree::Group(group) => {
+				let (open, close) = match group.delimiter() {
+					Delimiter::Parenthesis => ("(", ")"),
+					Delimiter::Bracket => ("[", "]"),
+					_ => ("", ""),
+				};
+				if !joined && !line.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '>') {
+					line.push(' ');
+				}
+				let mut inner = String::new();
+				write_tokens(group.stream(), 0, &mut inner);
+				line.push_str(open);
+				line.push_str(&inner.lines().map(str::trim).collect::<Vec<_>>().join(" "));
+				line.push_str(close);
+				// An attribute is on a line of its own.
+				if group.delimiter() == Delimiter::Bracket && (line.starts_with('#') && line.ends_with(']')) {
+					flush(&mut line, output);
+					joined = true;
+				} else {
+					joined = false;
+				}
+			}
+			TokenTree::Punct(punct) => {
+				let c = punct.as_char();
+				// The `;` after a block, like the one of `const _: () = { ... };`, goes on the line that closes it.
+				if c == ';' && line.is_empty() && output.ends_with("}\n") {
+					output.pop();
+					output.push_str(";\n");
+					joined = true;
+					continue;
+				}
+				let follows_ident = line.ends_with(|c: char| c.is_alphanumeric() || c == '_');
+				let follows_value = follows_ident || line.ends_with([')', ']']);
+				// A keyword, like `dyn`, is followed by a path, which stands on its own.
+				let last_word = line.rsplit(|c: char| !(c.is_alphanumeric() || c == '_')).next().unwrap_or("");
+				let keyword = matches!(last_word, "as" | "dyn" | "impl" | "in" | "mut" | "const" | "return" | "where");
+				let attached = match c {
+					',' | ';' | '.' | '>' => true,
+					// A `:` belongs to the name before it, as does a `::`, while the second `:` belongs to the first.
+					':' => (follows_ident && !keyword) || line.ends_with('>') || (line.ends_with(':') && after_joint),
+					// A `<` belongs to the name or the keyword before it, or to a closing `>`, but not to an arrow.
+					'<' => (follows_ident || line.ends_with('>')) && !line.ends_with("->"),
+					// The `!` of a macro belongs to its name.
+					'!' => follows_ident,
+					_ => false,
+				};
+				if !joined && !attached {
+					line.push(' ');
+				}
+				line.push(c);
+				// A prefix, like the `&` of a reference, or the `?` of `?Sized`, is written straight before what it applies to.
+				let prefix = matches!(c, '#' | '.' | '&' | '<') || (c == '!' && follows_ident) || (matches!(c, '?' | '*') && !follows_value);
+				joined = punct.spacing() == Spacing::Joint || prefix || (line.ends_with("::") && after_joint);
+				if c == ';' {
+					flush(&mut line, output);
+					joined = true;
+				}
+			}
+			tt => {
+				if !joined {
+					line.push(' ');
+				}
+				line.push_str(&tt.to_string());
+				joined = false;
+			}
+		}
+	}
+	flush(&mut line, output);
+}
+
+/// The platforms that can be used as a shorthand for a pathed attribute, and the `target_os` that they stand for.
+const PLATFORMS: &[(&str, &str)] = &[
+	("linux", "linux"),
+	("macos", "macos"),
+	("windows", "windows"),
+];
+
+/// Expands a platform shorthand (`#[linux = "sys/nix.rs"]`) to the attribute and path that it stands for.
+fn platform_shorthand(attr: &Attribute) -> Option<(Attribute, LitStr)> {
+	if let Some(Meta::NameValue(MetaNameValue { ident, lit: Lit::Str(path), .. })) = attr.interpret_meta() {
+		let (_platform, target_os) = PLATFORMS.iter()
+			.find(|(platform, _target_os)| ident == platform)?;
+		let attr = parse_quote!(#[cfg(target_os = #target_os)]);
+		Some((attr, path))
+	} else {
+		None
+	}
+}
+
+/// Resolves a path that refers to the path of an earlier attribute (`"~key"`), otherwise the path is returned as is.
+/// If the alias can't be resolved, an error is emitted, and nothing is returned.
+fn resolve_path_alias(attr: &Attribute, path: LitStr, pathed_attrs: &[(Attribute, LitStr)]) -> Option<LitStr> {
+	let value = path.value();
+	if !value.starts_with('~') {
+		return Some(path);
+	}
+	let alias = &value[1..];
+	if attr_key(attr).is_some_and(|key| key == alias) {
+		path.span()
+			.error(format!("The path alias \"~{}\" refers to itself.", alias))
+			.emit();
+		return None;
+	}
+	let target = pathed_attrs.iter()
+		.find(|(attr, _path)| attr_key(attr).is_some_and(|key| key == alias));
+	if let Some((_attr, target)) = target {
+		Some(LitStr::new(&target.value(), path.span()))
+	} else {
+		path.span()
+			.error(format!("The path alias \"~{}\" doesn't refer to any of the attributes declared before it.", alias))
+			.emit();
+		None
+	}
+}
+
+/// The key that a path alias uses to refer to an attribute.
+/// `#[cfg(unix)]` has the key `unix`, and `#[cfg(target_os = "macos")]` has the key `macos`.
+fn attr_key(attr: &Attribute) -> Option<String> {
+	match attr.interpret_meta()? {
+		Meta::List(ref list) if list.ident == "cfg" && list.nested.len() == 1 => {
+			match list.nested.first()?.into_value() {
+				NestedMeta::Meta(Meta::Word(ident)) => Some(ident.to_string()),
+				NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Str(lit), .. })) => Some(lit.value()),
+				_ => None,
+			}
+		}
+		_ => None,
+	}
+}
+
+/// The path to a module, which is relative to where `def_mod!` was invoked, unless it was given a root: `self::net::tcp`
+struct ModulePath {
+	/// The path that the module is declared under, which replaces `self`: `#[root = "crate::external"]`
+	root: Option<Path>,
+	segments: Vec<Ident>,
+	/// Whether the checks import everything from the module, or name what they check by its path: `#[no_glob]`
+	glob: bool,
+	/// How a method is assigned to its pointer: `#[assert_style = "let"]`
+	assert_style: AssertStyle,
+	/// The types that a signature is warned about naming, as they need std, which is empty unless asked for: `#[no_std]`
+	no_std: Vec<String>,
+	/// What the generated items are named with, which is `_` unless it's given: `#[prefix = "__dm_"]`
+	prefix: String,
+}
+
+/// How a method that isn't generic is assigned to its pointer.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "derive-debug", derive(Debug))]
+enum AssertStyle {
+	/// `const _ASSERT_METHOD_0: fn(u32) -> u8 = self::my_mod::method;`
+	Const,
+	/// `let _ASSERT_METHOD_0: fn(u32) -> u8 = self::my_mod::method;`
+	/// The binding is part of the load function's body, instead of being an item of its own, so a mismatch is reported against it.
+	Let,
+}
+
+impl ModulePath {
+	fn new(root: Option<Path>, ident: &Ident) -> Self {
+		// The root is spanned to the literal it was parsed from, which isn't where the checks are, much like `self` isn't.
+		let root = root.map(|root| {
+			let ts = root.into_token_stream()
+				.into_iter()
+				.map(|mut tt| {
+					tt.set_span(Span::call_site());
+					tt
+				})
+				.collect();
+			Path::parse_mod_style.parse2(ts).expect("Should never happen [root]")
+		});
+		ModulePath {
+			root,
+			segments: vec![ident.clone()],
+			glob: true,
+			assert_style: AssertStyle::Const,
+			no_std: vec![],
+			prefix: "_".to_owned(),
+		}
+	}
+
+	fn join(&self, ident: &Ident) -> Self {
+		let mut segments = self.segments.clone();
+		segments.push(ident.clone());
+		ModulePath {
+			root: self.root.clone(),
+			segments,
+			glob: self.glob,
+			assert_style: self.assert_style,
+			no_std: self.no_std.clone(),
+			prefix: self.prefix.clone(),
+		}
+	}
+
+	/// The path as a single name, which is used to name the generated functions: `net_tcp`
+	fn flat_name(&self) -> String {
+		self.segments.iter()
+			.map(|segment| segment.to_string())
+			.collect::<Vec<_>>()
+			.join("_")
+	}
+
+	/// One of the names that the checks use, which starts with the prefix: `_assert_obj`
+	fn prefixed(&self, name: &str, span: Span) -> Ident {
+		Ident::new(&format!("{}{}", self.prefix, name), span)
+	}
+
+	/// The path to one of the module's ancestors, where `1` is its parent: `self::net`
+	/// Once it goes past where `def_mod!` was invoked, it continues with `super`.
+	fn ancestor(&self, levels: usize) -> Path {
+		let (leading_colon, mut segments) = match self.root {
+			Some(ref root) => (root.leading_colon, root.segments.iter().map(|segment| segment.ident.clone()).collect()),
+			None => (None, vec![Ident::new("self", Span::call_site())]),
+		};
+		segments.extend(self.segments.iter().cloned());
+		for _ in 0..levels {
+			match segments.last_mut() {
+				Some(last) if last == "self" => *last = Ident::new("super", last.span()),
+				// It can't go any higher than the crate, but that's left for the compiler to complain about.
+				Some(last) if last == "super" || last == "crate" => segments.push(Ident::new("super", Span::call_site())),
+				Some(_) => {
+					segments.pop();
+				}
+				None => segments.push(Ident::new("super", Span::call_site())),
+			}
+		}
+		Path {
+			leading_colon,
+			segments: segments.into_iter().map(PathSegment::from).collect(),
+		}
+	}
+
+	/// The path as seen from a module that's generated next to the checks, so anything relative needs to go up a level first.
+	/// An absolute root (`crate::` or `::`) is the same from anywhere.
+	fn relative_to_child(&self) -> TokenStream {
+		let segments = &self.segments;
+		let root = match self.root {
+			None => quote!(super),
+			Some(ref root) if root.leading_colon.is_some() => quote!(#root),
+			Some(ref root) => {
+				let first = &root.segments.first().expect("Should never happen [root]").value().ident;
+				if first == "crate" {
+					quote!(#root)
+				} else if first == "self" {
+					let rest = root.segments.iter().skip(1);
+					quote!(super #(::#rest)*)
+				} else {
+					quote!(super::#root)
+				}
+			}
+		};
+		quote!(#root::#(#segments)::*)
+	}
+
+	/// The visibility that an item of this module would have if it were re-exported from a module generated next to the checks.
+	/// A relative restriction (`pub(super)`) is relative to this module, so it has to be moved up to where the checks are,
+	/// which isn't possible if it names a module that's under the declared one, or under a root.
+	fn child_visibility(&self, vis: &Visibility) -> Option<Visibility> {
+		let restricted = match vis {
+			Visibility::Restricted(restricted) => restricted,
+			vis => return Some(vis.clone()),
+		};
+		let path = &restricted.path;
+		let mut segments = path.segments.iter()
+			.map(|segment| &segment.ident)
+			.peekable();
+		if path.leading_colon.is_some() || segments.peek().is_some_and(|first| *first == "crate") {
+			return Some(vis.clone());
+		}
+		if segments.peek().is_some_and(|first| *first == "self") {
+			segments.next();
+		}
+		let levels = segments.clone()
+			.take_while(|segment| *segment == "super")
+			.count();
+		// `pub(self)` is as good as private, so there's nothing to check.
+		if levels == 0 || segments.count() != levels || self.root.is_some() || levels < self.segments.len() {
+			return None;
+		}
+		let supers = (self.segments.len()..=levels).map(|_| Ident::new("super", restricted.span()));
+		Some(parse_quote!(pub(in #(#supers)::*)))
+	}
+}
+
+impl ToTokens for ModulePath {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let segments = &self.segments;
+		let root = match self.root {
+			Some(ref root) => quote!(#root),
+			None => quote!(self),
+		};
+		quote!(#root::#(#segments)::*).to_tokens(tokens);
+	}
+}
+
+/// Rewrites the paths that start with `super`, which are relative to the declared module, so they're relative to where the checks are instead.
+/// `super::other::Thing` inside of `my_mod` turns into `self::other::Thing`.
+struct SuperResolver<'a>(&'a ModulePath);
+
+impl<'a> VisitMut for SuperResolver<'a> {
+	fn visit_path_mut(&mut self, path: &mut Path) {
+		visit_mut::visit_path_mut(self, path);
+		if path.leading_colon.is_some() {
+			return;
+		}
+		let levels = path.segments.iter()
+			.take_while(|segment| segment.ident == "super")
+			.count();
+		if levels == 0 {
+			return;
+		}
+		let ancestor = self.0.ancestor(levels);
+		let segments = ancestor.segments.into_iter()
+			.chain(path.segments.iter().skip(levels).cloned())
+			.collect();
+		*path = Path {
+			leading_colon: ancestor.leading_colon,
+			segments,
+		};
+	}
+
+	fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+		if let Some(ref mut qself) = type_path.qself {
+			self.visit_qself_mut(qself);
+		}
+		let before = type_path.path.segments.len();
+		self.visit_path_mut(&mut type_path.path);
+		// The position counts the segments of the trait, so it has to account for the ones that were added.
+		if let Some(ref mut qself) = type_path.qself {
+			qself.position += type_path.path.segments.len() - before;
+		}
+	}
+}
+
+/// Qualifies the paths to a trait's associated types, so `Self::Out` turns into `<Self as Encoder>::Out`.
+/// Only the declared ones are qualified, as any others could be from a supertrait, which can't be named through the trait.
+struct AssocQualifier<'a> {
+	trait_name: &'a Ident,
+	names: &'a [Ident],
+}
+
+impl<'a> VisitMut for AssocQualifier<'a> {
+	fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+		visit_mut::visit_type_path_mut(self, type_path);
+		if type_path.qself.is_some() || type_path.path.leading_colon.is_some() {
+			return;
+		}
+		let segments = &type_path.path.segments;
+		let declared = match (segments.iter().next(), segments.iter().nth(1)) {
+			(Some(first), Some(second)) => is_self_keyword(&first.ident) && self.names.contains(&second.ident),
+			_ => false,
+		};
+		if declared {
+			let self_ident = &segments[0].ident;
+			let trait_name = self.trait_name;
+			let rest = segments.iter().skip(1);
+			*type_path = parse_quote!(<#self_ident as #trait_name>::#(#rest)::*);
+		}
+	}
+}
+
+/// The type that methods are checked against, and what `Self` is replaced with.
+struct SelfType<'a> {
+	ident: &'a Ident,
+	/// The path that the type is named by, which is just its name if it was imported.
+	name: TokenStream,
+	ty: TokenStream,
+	/// The generic parameters that are needed to name the type, if there are any.
+	generics: Option<&'a Generics>,
+}
+
+impl<'a> SelfType<'a> {
+	fn new(ident: &'a Ident, name: TokenStream, generics: &'a Generics) -> Self {
+		let (_impl_generics, ty_generics, _where_clause) = generics.split_for_impl();
+		SelfType {
+			ident,
+			ty: quote!(#name #ty_generics),
+			name,
+			generics: if generics.params.is_empty() {
+				None
+			} else {
+				Some(generics)
+			},
+		}
+	}
+}
+
+/// Transforms a method into an assertion, replacing `Self` with the type if it was declared inside of one.
+/// A body is an error, but it's stripped instead of dropping the method, so the signature is still checked.
+fn tokenise_method(module_path: &ModulePath, self_type: Option<&SelfType>, index: &mut u32, mut method_item: TraitItemMethod) -> TokenStream {
+	if let Some(body) = method_item.default.take() {
+		body.span()
+			.error("A body isn't valid here, only the signature is checked. [Hint: Replace the body with `;`.]")
+			.emit();
+	}
+	let t = convert_method(module_path, self_type, *index, method_item);
+	*index += 1;
+	t
+}
+
+/// Transforms a function of a type into the same check as a function of a module, with the type standing in for the module.
+/// None of the names are replaced, so `Self` isn't available, and neither is a receiver.
+fn tokenise_assoc_fn(module_path: &ModulePath, index: &mut u32, assoc_item: AssocFnDecl) -> TokenStream {
+	let AssocFnDecl {
+		type_name,
+		mut method_item,
+	} = assoc_item;
+	if let Some(receiver) = method_item.sig.decl.inputs.iter().next().filter(|arg| is_receiver(arg)) {
+		receiver.span()
+			.error(format!("`{}` has a receiver, so it has to be declared inside of its type. [Hint: Move it into `type {} {{ ... }}`.]", method_item.sig.ident, type_name))
+			.emit();
+		return TokenStream::new();
+	}
+	// It'd only be reported as missing from where the check is, so it's left out instead.
+	let decl = &method_item.sig.decl;
+	let (generics, inputs, output) = (&decl.generics, &decl.inputs, &decl.output);
+	let where_clause = &generics.where_clause;
+	if let Some(self_span) = find_self_type(quote!(#generics #inputs #output #where_clause)) {
+		self_span
+			.error(format!("`Self` isn't available to `{}`, as it's declared outside of its type. [Hint: Name the type instead, or move it into `type {} {{ ... }}`.]", method_item.sig.ident, type_name))
+			.emit();
+		return TokenStream::new();
+	}
+	// The const is still one of the module's, and not the type's.
+	let const_check = take_attr(&mut method_item.attrs, "as_const")
+		.and_then(|attr| as_const_name(&attr))
+		.map(|const_name| tokenise_as_const(module_path, index, &method_item, &const_name));
+	let check = tokenise_method(&module_path.join(&type_name), None, index, method_item);
+	quote! {
+		#check
+		#const_check
+	}
+}
+
+/// Transforms the const that a function is paired with, `as const HANDLER`, into a const of the function's pointer type that's assigned to it.
+/// The pointer has to name every type, so a function with generics, other than lifetimes, or a `_` can't be paired with one.
+fn tokenise_as_const(module_path: &ModulePath, index: &mut u32, method_item: &TraitItemMethod, const_name: &Ident) -> TokenStream {
+	let sig = &method_item.sig;
+	let generic = sig.decl.generics.params.iter()
+		.any(|param| !matches!(param, GenericParam::Lifetime(_)));
+	if generic || sig.asyncness.is_some() || has_wildcard(&sig.decl) {
+		const_name.span()
+			.error(format!("`{}` can't be stored in a const, as its pointer type can't be named. [Hint: It can't have generics other than lifetimes, be async, or have a `_`.]", sig.ident))
+			.emit();
+		return TokenStream::new();
+	}
+	let lifetimes = if sig.decl.generics.params.is_empty() {
+		None
+	} else {
+		let params = &sig.decl.generics.params;
+		Some(quote!(for<#params>))
+	};
+	let unsafety = &sig.unsafety;
+	let abi = &sig.abi;
+	let inputs = sig.decl.inputs.iter()
+		.map(|arg| match arg {
+			FnArg::Captured(arg) => arg.ty.clone().into_token_stream(),
+			FnArg::Ignored(ty) => ty.clone().into_token_stream(),
+			// A receiver isn't valid outside of a type, which the function's own check reports.
+			arg => arg.clone().into_token_stream(),
+		});
+	let output = &sig.decl.output;
+	let attrs = &check_attrs(&method_item.attrs);
+	let load_ident = {
+		let name = format!("{}ASSERT_CONST_{}", module_path.prefix, index);
+		Ident::new(&name, const_name.span())
+	};
+	*index += 1;
+	let value = respan(quote!(#module_path::#const_name), const_name.span());
+	quote_spanned! { const_name.span() =>
+		#(#attrs)*
+		const #load_ident: #lifetimes #unsafety #abi fn(#(#inputs),*) #output = #value;
+	}
+}
+
+/// Transforms a type into a scope that imports it, which contains the checks for everything that was declared inside of it.
+/// If the type is generic, the checks are wrapped in a function that declares the parameters.
+fn tokenise_type(module_path: &ModulePath, index: &mut u32, type_item: TypeDecl) -> TokenStream {
+	let TypeDecl {
+		mut attrs,
+		ident: type_name,
+		generics,
+		bounds,
+		body,
+	} = type_item;
+	let error = take_attr(&mut attrs, "error");
+	// `#[size = 24]` is the same as declaring `size = 24` in the body, which a type without one can't do.
+	let layout_entries: Punctuated<LayoutEntry, Token![,]> = ["size", "align"].iter()
+		.filter_map(|key| take_attr(&mut attrs, key).and_then(|attr| layout_entry(key, &attr)))
+		.collect();
+	// Without the glob, the type isn't imported either, so a name that it shadows is left alone.
+	let (import, name) = if module_path.glob {
+		(Some(tokenise_import(module_path, &type_name)), type_name.clone().into_token_stream())
+	} else {
+		(None, quote!(#module_path::#type_name))
+	};
+	let self_type = SelfType::new(&type_name, name, &generics);
+	let defaults_check = tokenise_defaults(module_path, &self_type.name, &generics, index);
+
+	let bounds_check = if bounds.is_empty() {
+		None
+	} else {
+		// The bounds are checked by calling a function that requires them, as a where clause on its own isn't enforced.
+		let assert_name = {
+			let name = format!("{}assert_bounds_{}", module_path.prefix, index);
+			Ident::new(&name, type_name.span())
+		};
+		*index += 1;
+		let self_ty = &self_type.ty;
+		let bounds = replace_idents(bounds.into_token_stream(), &replace_self(self_ty));
+		Some(quote_spanned! { type_name.span() =>
+			fn #assert_name<T: #bounds>() {}
+			#assert_name::<#self_ty>();
+		})
+	};
+
+	let type_items: Vec<TokenStream> = if let TypeDeclBody::Content((_brace, body)) = body {
+		body.into_iter()
+			.map(|item| match item {
+				TypeItem::Method(mut method_item) => {
+					// The type's error is used for any of its methods that don't declare their own.
+					if let Some(ref error) = error {
+						if !method_item.attrs.iter().any(|attr| is_attr(attr, "error")) {
+							method_item.attrs.push(error.clone());
+						}
+					}
+					tokenise_method(module_path, Some(&self_type), index, method_item)
+				}
+				TypeItem::Const(const_item) => {
+					let attrs = &check_attrs(&const_item.attrs);
+					let ident = &const_item.ident;
+					// `Self` can't be referenced from the generated constant, so it's replaced with the type, such as in a stored pointer: `fn(&Self) -> u8`
+					let ty = replace_idents(const_item.ty.into_token_stream(), &replace_self(&self_type.ty));
+					let load_ident = {
+						let name = format!("{}ASSERT_ASSOC_CONST_{}", module_path.prefix, index);
+						Ident::new(&name, ident.span())
+					};
+					*index += 1;
+					if self_type.generics.is_some() {
+						// A const can't reference the parameters of the function it's in.
+						let self_ty = &self_type.ty;
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							let #load_ident: #ty = <#self_ty>::#ident;
+						}
+					} else {
+						let name = &self_type.name;
+						let value = respan(quote!(#name::#ident), ident.span());
+						quote_spanned! { ident.span() =>
+							#(#attrs)*
+							const #load_ident: #ty = #value;
+						}
+					}
+				}
+				TypeItem::Type(assoc_item) => tokenise_assoc_type(&self_type, assoc_item),
+				TypeItem::Field(field_item) => tokenise_field(&self_type, field_item),
+				TypeItem::Constructor(constructor_item) => tokenise_constructor(&self_type, constructor_item),
+				TypeItem::Layout(layout_item) => tokenise_layout(&self_type, layout_item),
+				TypeItem::Op(op_item) => tokenise_op(&self_type, op_item),
+			})
+			.collect()
+	} else {
+		vec![]
+	};
+	let layout_check = if layout_entries.is_empty() {
+		None
+	} else {
+		Some(tokenise_layout(&self_type, LayoutDecl {
+			attrs: vec![],
+			entries: layout_entries,
+		}))
+	};
+	// Naming the type with the declared parameters means the real one can't require more of them than was declared, or take a different number of them.
+	let params_check = self_type.generics.map(|_| {
+		let self_ty = &self_type.ty;
+		quote_spanned! { type_name.span() =>
+			let _ = ::core::marker::PhantomData::<#self_ty>;
+		}
+	});
+	let type_items: Vec<TokenStream> = params_check.into_iter()
+		.chain(bounds_check)
+		.chain(layout_check)
+		.chain(type_items)
+		.collect();
+
+	let type_items = if self_type.generics.is_some() {
+		let checker_name = {
+			let name = format!("{}check_{}", module_path.prefix, type_name);
+			Ident::new(&name, type_name.span())
+		};
+		let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
+		quote! {
+			#[allow(non_snake_case)]
+			fn #checker_name #impl_generics() #where_clause {
+				#(#type_items)*
+			}
+		}
+	} else {
+		quote! {
+			#(#type_items)*
+		}
+	};
+
+	// We use the actual use declaration here to test for the type itself, as it'll fail if it doesn't exist or not exported.
+	// It also makes the codegen easier, because we don't have to qualify the full name type.
+	let attrs = check_attrs(&attrs);
+	quote! {
+		#(#attrs)*
+		{
+			#import
+			#defaults_check
+			#type_items
+		}
+	}
+}
+
+/// Transforms an alias into a function that returns its parameter, which only compiles if the alias and the target are the same type.
+fn tokenise_alias(module_path: &ModulePath, index: &mut u32, alias_item: AliasDecl) -> TokenStream {
+	let attrs = &check_attrs(&alias_item.attrs);
+	let ident = &alias_item.ident;
+	let ty = &alias_item.ty;
+	let assert_name = {
+		let name = format!("{}assert_alias_{}", module_path.prefix, index);
+		Ident::new(&name, ident.span())
+	};
+	*index += 1;
+	let defaults_check = tokenise_defaults(module_path, &quote!(#module_path::#ident), &alias_item.generics, index);
+	let (impl_generics, ty_generics, where_clause) = alias_item.generics.split_for_impl();
+	quote_spanned! { ident.span() =>
+		#(#attrs)*
+		#[allow(non_snake_case)]
+		fn #assert_name #impl_generics(value: #ty) -> #module_path::#ident #ty_generics #where_clause {
+			value
+		}
+		#(#attrs)*
+		#defaults_check
+	}
+}
+
+/// Names the type without the parameters that have a default, and returns it as the type with the defaults given to it.
+/// That only compiles if they're the defaults that the type actually has, as they'd be different types otherwise.
+fn tokenise_defaults(module_path: &ModulePath, name: &TokenStream, generics: &Generics, index: &mut u32) -> Option<TokenStream> {
+	// The default of a const parameter can't be parsed, as the `>` that follows it is taken as part of the expression.
+	let defaults: Vec<&Type> = generics.type_params()
+		.filter_map(|type_param| type_param.default.as_ref())
+		.collect();
+	let span = defaults.first()?.span();
+	// The module's path is spanned to the call site, so the error would point at the whole macro otherwise.
+	let name = &respan(name.clone(), span);
+	let params: Vec<&GenericParam> = generics.params.iter()
+		.filter(|param| !matches!(param, GenericParam::Type(TypeParam { default: Some(_), .. })))
+		.collect();
+	let args = &params.iter()
+		.map(|param| match param {
+			GenericParam::Type(type_param) => type_param.ident.clone().into_token_stream(),
+			GenericParam::Const(const_param) => const_param.ident.clone().into_token_stream(),
+			GenericParam::Lifetime(def) => def.lifetime.clone().into_token_stream(),
+		})
+		.collect::<Vec<_>>();
+	let assert_name = {
+		let name = format!("{}assert_defaults_{}", module_path.prefix, index);
+		Ident::new(&name, span)
+	};
+	*index += 1;
+	Some(quote_spanned! { span =>
+		#[allow(non_snake_case)]
+		fn #assert_name<#(#params),*>(value: #name<#(#args),*>) -> #name<#(#args,)* #(#defaults),*> {
+			value
+		}
+	})
+}
+
+/// A default is only used when a type is named, so it's an error on any other parameter, rather than being left out without a word.
+fn lint_defaults(generics: &Generics) {
+	for default in generics.type_params().filter_map(|type_param| type_param.default.as_ref()) {
+		default.span()
+			.error("A default can only be given to the parameters of a type or an alias. [Hint: Remove it, as it'd never be used here.]")
+			.emit();
+	}
+}
+
+/// Transforms an implementation into a call to a function that's bound by the trait, which only compiles if the type implements it.
+/// If the implementation is generic, the call is made from a function that declares the parameters, so it has to hold for all of them.
+/// The methods in its body are checked in that same function, through the trait, as `<MyStruct as Encoder>::finish`.
+/// That only makes sure the trait has the method with that signature, as a default can't be told apart from one that the type overrides.
+fn tokenise_impl(module_path: &ModulePath, index: &mut u32, impl_item: ImplDecl) -> TokenStream {
+	let attrs = &check_attrs(&impl_item.attrs);
+	let trait_path = &impl_item.trait_path;
+	let self_ty = &impl_item.self_ty;
+	let span = impl_item.impl_token.span;
+	let assert_name = {
+		let name = format!("{}assert_impl_{}", module_path.prefix, index);
+		Ident::new(&name, span)
+	};
+	let checker_name = {
+		let name = format!("{}check_impl_{}", module_path.prefix, index);
+		Ident::new(&name, span)
+	};
+	*index += 1;
+	lint_defaults(&impl_item.generics);
+
+	// The trait can name the parameters, so the function has to declare them too, and they're passed along by name.
+	// The lifetimes are left out, as they can't be given explicitly alongside the inferred ones.
+	let bound: Generics = parse_quote!(<_Self: ?Sized + #trait_path>);
+	let assert_generics = merge_generics(&impl_item.generics, bound);
+	let (assert_impl_generics, _ty_generics, assert_where_clause) = assert_generics.split_for_impl();
+	let args = impl_item.generics.params
+		.iter()
+		.filter_map(|param| match param {
+			GenericParam::Type(type_param) => Some(&type_param.ident),
+			GenericParam::Const(const_param) => Some(&const_param.ident),
+			GenericParam::Lifetime(_) => None,
+		});
+	let trait_name = &trait_path.segments.last()
+		.expect("A path always has a segment.")
+		.value()
+		.ident;
+	let self_type = SelfType {
+		ident: trait_name,
+		name: quote!(<#self_ty as #trait_path>),
+		ty: self_ty.into_token_stream(),
+		generics: if impl_item.generics.params.is_empty() {
+			None
+		} else {
+			Some(&impl_item.generics)
+		},
+	};
+	let method_items: Vec<TokenStream> = impl_item.items
+		.into_iter()
+		.map(|method_item| tokenise_method(module_path, Some(&self_type), index, method_item))
+		.collect();
+	let (impl_generics, _ty_generics, where_clause) = impl_item.generics.split_for_impl();
+	quote_spanned! { span =>
+		#(#attrs)*
+		{
+			#[allow(non_snake_case)]
+			fn #assert_name #assert_impl_generics() #assert_where_clause {}
+			#[allow(non_snake_case)]
+			fn #checker_name #impl_generics() #where_clause {
+				#assert_name::<#(#args,)* #self_ty>();
+				#(#method_items)*
+			}
+		}
+	}
+}
+
+/// Transforms a trait into a generic function that's bound by it, which also makes sure it is one.
+/// The methods are then checked against the generic type.
+fn tokenise_trait(module_path: &ModulePath, index: &mut u32, trait_item: TraitDecl) -> TokenStream {
+	let attrs = &check_attrs(&trait_item.attrs);
+	let trait_name = &trait_item.ident;
+
+	let self_ident = Ident::new("_Self", trait_name.span());
+	let generics: Generics = parse_quote!(<#self_ident: #trait_name>);
+	let self_type = SelfType {
+		ident: &self_ident,
+		name: self_ident.clone().into_token_stream(),
+		ty: self_ident.clone().into_token_stream(),
+		generics: Some(&generics),
+	};
+
+	let body = match trait_item.body {
+		TraitDeclBody::Content((_brace, body)) => body,
+		TraitDeclBody::Terminated(_) => vec![],
+	};
+	let assoc_names: Vec<Ident> = body.iter()
+		.filter_map(|item| match item {
+			TraitDeclItem::Type(assoc_item) => Some(assoc_item.ident.clone()),
+			TraitDeclItem::Method(_) => None,
+		})
+		.collect();
+	let qualifier = &mut AssocQualifier {
+		trait_name,
+		names: &assoc_names,
+	};
+	let method_items: Vec<TokenStream> = body.into_iter()
+		.map(|item| match item {
+			TraitDeclItem::Method(mut method_item) => {
+				qualifier.visit_trait_item_method_mut(&mut method_item);
+				tokenise_method(module_path, Some(&self_type), index, method_item)
+			}
+			TraitDeclItem::Type(mut assoc_item) => {
+				qualifier.visit_trait_item_type_mut(&mut assoc_item);
+				tokenise_trait_assoc_type(module_path, &self_type, trait_name, assoc_item)
+			}
+		})
+		.collect();
+
+	let checker_name = {
+		let name = format!("{}check_{}", module_path.prefix, trait_name);
+		Ident::new(&name, trait_name.span())
+	};
+	let import = tokenise_import(module_path, trait_name);
+	quote! {
+		#(#attrs)*
+		{
+			#import
+			#[allow(non_snake_case)]
+			fn #checker_name #generics() {
+				#(#method_items)*
+			}
+		}
+	}
+}
+
+/// Takes the methods out of a module that's #[object_safe], as they're the trait's, and checks them against its trait object.
+/// Naming `dyn Trait` at all fails if the trait isn't object safe, and each method is then checked as if it was declared on `dyn Trait`.
+/// The object is `'static`, as that's the lifetime it's given when one of its methods is named, which the pointer has to agree with.
+fn tokenise_object_safe(module_path: &ModulePath, index: &mut u32, trait_name: &Ident, body: Vec<(Visibility, DeclItem)>) -> (Option<TokenStream>, Vec<(Visibility, DeclItem)>) {
+	let self_type = SelfType {
+		ident: trait_name,
+		name: quote!(<dyn #trait_name + 'static>),
+		ty: quote!((dyn #trait_name + 'static)),
+		generics: None,
+	};
+	let mut method_items = vec![];
+	let mut rest = vec![];
+	for (vis, mut item) in body {
+		let method_item = match item {
+			DeclItem::Method(ref method_item) => method_item,
+			_ => {
+				rest.push((vis, item));
+				continue;
+			}
+		};
+		if !matches!(vis, Visibility::Inherited) {
+			vis.span()
+				.error("A method of a trait doesn't have a visibility, it's as visible as the trait is.")
+				.emit();
+			continue;
+		}
+		let ident = &method_item.sig.ident;
+		match method_item.sig.decl.inputs.iter().next() {
+			Some(receiver @ FnArg::SelfValue(_)) => {
+				receiver.span()
+					.error(format!("`{}` takes `self` by value, so it can't be called on a trait object. [Hint: Take it by reference, or as `self: Box<Self>`.]", ident))
+					.emit();
+				continue;
+			}
+			Some(receiver) if is_receiver(receiver) => {}
+			_ => {
+				ident.span()
+					.error(format!("`{}` doesn't have a receiver, so it can't be called on a trait object. [Hint: Declare it on a type instead.]", ident))
+					.emit();
+				continue;
+			}
+		}
+		lint_no_std(module_path, &item.signatures());
+		item.resolve_super(module_path);
+		if let DeclItem::Method(method_item) = item {
+			method_items.push(tokenise_method(module_path, Some(&self_type), index, method_item));
+		}
+	}
+
+	let import = tokenise_import(module_path, trait_name);
+	let assert_obj = module_path.prefixed("assert_obj", trait_name.span());
+	// The object needs its parentheses behind a reference, but not as a parameter, like in `Box<Self>`.
+	let check = quote_spanned! { trait_name.span() =>
+		#[allow(unused_parens)]
+		{
+			#import
+			fn #assert_obj(_: &dyn #trait_name) {}
+			#(#method_items)*
+		}
+	};
+	(Some(check), rest)
+}
+
+/// Transforms an associated type of a trait into a call that names it, which is bounded by the declared bounds.
+/// The bounds of the trait's own declaration are the only thing that's known about the type, so that's what they're checked against.
+fn tokenise_trait_assoc_type(module_path: &ModulePath, self_type: &SelfType, trait_name: &Ident, assoc_item: TraitItemType) -> TokenStream {
+	let ident = &assoc_item.ident;
+	if let Some((eq_token, _ty)) = assoc_item.default {
+		eq_token.span()
+			.error("A default isn't valid here, only the bounds are checked. [Hint: Remove the `= ...`.]")
+			.emit();
+	}
+	if !assoc_item.generics.params.is_empty() {
+		assoc_item.generics.span()
+			.warning("A generic associated type can't be named without its parameters, so it isn't checked.")
+			.emit();
+		return TokenStream::new();
+	}
+	let self_ty = &self_type.ty;
+	let bounds = replace_idents(assoc_item.bounds.into_token_stream(), &replace_self(self_ty));
+	let attrs = &check_attrs(&assoc_item.attrs);
+	let assert_assoc = module_path.prefixed("assert_assoc", ident.span());
+	quote_spanned! { ident.span() =>
+		#(#attrs)*
+		{
+			fn #assert_assoc<T: ?Sized + #bounds>() {}
+			#assert_assoc::<<#self_ty as #trait_name>::#ident>();
+		}
+	}
+}
+
+/// Generates a trait from the functions of a module's body, with each of them taking `&self`.
+/// It's declared inside of its own module, so the signatures can still name anything that the module exports.
+/// Both are gated the same way as the checks, as the module might not be there.
+fn tokenise_as_trait(module_path: &ModulePath, gate: &TokenStream, vis: &Visibility, trait_name: &Ident, body: &[(Visibility, DeclItem)]) -> TokenStream {
+	let child_path = module_path.relative_to_child();
+	let mut trait_items = vec![];
+	for (_vis, item) in body {
+		match item {
+			DeclItem::Method(method_item) => trait_items.extend(as_trait_item(None, method_item)),
+			DeclItem::Type(type_item) => {
+				if let TypeDeclBody::Content((_brace, ref items)) = type_item.body {
+					let type_name = &type_item.ident;
+					let self_ty = if module_path.glob {
+						quote!(#type_name)
+					} else {
+						quote!(#child_path::#type_name)
+					};
+					for item in items {
+						if let TypeItem::Method(method_item) = item {
+							if type_item.generics.params.is_empty() {
+								trait_items.extend(as_trait_item(Some(&self_ty), method_item));
+							} else {
+								method_item.sig.ident.span()
+									.warning("A method of a generic type can't be part of the trait, so it'll be skipped.")
+									.emit();
+							}
+						}
+					}
+				}
+			}
+			// Everything else isn't a function, so there's nothing to add to the trait.
+			_ => {}
+		}
+	}
+
+	let trait_module = {
+		let name = format!("{}as_trait_{}", module_path.prefix, module_path.flat_name());
+		Ident::new(&name, trait_name.span())
+	};
+	let glob = if module_path.glob {
+		Some(quote! {
+			#[allow(unused_imports)]
+			use #child_path::*;
+		})
+	} else {
+		None
+	};
+	quote! {
+		#gate
+		#vis use self::#trait_module::#trait_name;
+		#gate
+		#[allow(non_snake_case)]
+		mod #trait_module {
+			#[allow(unused_imports)]
+			use super::*;
+			#glob
+
+			pub trait #trait_name {
+				#(#trait_items)*
+			}
+		}
+	}
+}
+
+/// Transforms a method into a trait item that takes `&self`, replacing `Self` with the type if it was declared inside of one.
+fn as_trait_item(self_ty: Option<&TokenStream>, method: &TraitItemMethod) -> Option<TokenStream> {
+	let has_receiver = method.sig.decl.inputs
+		.iter()
+		.any(is_receiver);
+	if has_receiver {
+		method.sig.ident.span()
+			.warning("A method with a receiver can't be part of the trait, as it'd conflict with the trait's own, so it'll be skipped.")
+			.emit();
+		return None;
+	}
+	if has_wildcard(&method.sig.decl) {
+		method.sig.ident.span()
+			.warning("A method with a `_` can't be part of the trait, as each type has to be named, so it'll be skipped.")
+			.emit();
+		return None;
+	}
+
+	// A trait's methods can't be const, but the signature is still the same otherwise.
+	let mut sig = method.sig.clone();
+	sig.constness = None;
+	sig.decl.inputs.insert(0, parse_quote!(&self));
+	let attrs = method.attrs
+		.iter()
+		.filter(|attr| !is_attr(attr, "error") && !is_attr(attr, "check_names") && !is_attr(attr, "smoke_test") && !is_attr(attr, "strict_lifetimes") && !is_attr(attr, "as_const"));
+	let ts = quote! {
+		#(#attrs)*
+		#sig;
+	};
+	Some(if let Some(self_ty) = self_ty {
+		replace_idents(ts, &replace_self(self_ty))
+	} else {
+		ts
+	})
+}
+
+/// Transforms a nested module into a load function of its own, which is then called by the parent's.
+/// The parent's source is what declares the module, so a path only serves to pair the checks with the attribute.
+fn tokenise_module(parent_path: &ModulePath, index: &mut u32, module_item: ModuleDecl) -> TokenStream {
+	let (pathed_attrs, mut custom_attrs) = group_attrs(module_item.attrs);
+	// Much like any other path, it isn't needed, as the parent is what declares the module.
+	take_attr(&mut custom_attrs, "path_env");
+	if let Some(attr) = take_attr(&mut custom_attrs, "root") {
+		attr.span()
+			.error("A #[root] can only be given to a top-level module, as a nested one is always under its parent.")
+			.emit();
+	}
+	let sealed = take_attr(&mut custom_attrs, "sealed")
+		.and_then(|attr| sealed_names(&attr));
+	let load_fn = take_attr(&mut custom_attrs, "load_fn")
+		.and_then(|attr| load_fn_name(&attr));
+	let no_glob = take_attr(&mut custom_attrs, "no_glob").is_some();
+	let assert_style = take_attr(&mut custom_attrs, "assert_style")
+		.and_then(|attr| assert_style(&attr));
+	let no_std = take_attr(&mut custom_attrs, "no_std")
+		.and_then(|attr| no_std_types(&attr));
+	let prefix = take_attr(&mut custom_attrs, "prefix")
+		.and_then(|attr| prefix_name(&attr));
+	let object_safe = take_attr(&mut custom_attrs, "object_safe")
+		.and_then(|attr| object_safe_name(&attr));
+	if let Some(attr) = module_item.mod_attrs.first() {
+		attr.span()
+			.error("A nested module is declared by its parent, so there's no declaration for the attribute to go on. [Hint: Put it on the module's declaration in the parent instead.]")
+			.emit();
+	}
+	let load_attrs = &module_item.load_attrs;
+	let custom_attrs = check_attrs(&custom_attrs);
+	let ident = &module_item.ident;
+	// The parent's #[no_glob], #[no_std] and #[prefix] carry over, as the signatures are written the same way.
+	let mut module_path = parent_path.join(ident);
+	if no_glob {
+		module_path.glob = false;
+	}
+	if let Some(assert_style) = assert_style {
+		module_path.assert_style = assert_style;
+	}
+	if let Some(no_std) = no_std {
+		module_path.no_std = no_std;
+	}
+	if let Some(prefix) = prefix {
+		module_path.prefix = prefix;
+	}
+	let sealed_check = sealed.map(|names| tokenise_sealed(&module_path, &names));
+
+	let vis_check = tokenise_visibility(parent_path, &module_item.vis, ident, &[]);
+	let (object_check, items) = match module_item.body {
+		ModuleBody::Content((_brace, body)) => {
+			let (object_check, body) = match object_safe {
+				Some(ref trait_name) => tokenise_object_safe(&module_path, index, trait_name, body),
+				None => (None, body),
+			};
+			(object_check, tokenise_items(&module_path, index, body))
+		}
+		// The import is enough to check that the module exists.
+		ModuleBody::Terminated(_) => {
+			if let Some(trait_name) = object_safe {
+				trait_name.span()
+					.warning("The module doesn't declare a body, so there are no methods to call on the trait object. [Hint: Use `{}` as the body instead.]")
+					.emit();
+			}
+			(None, vec![])
+		}
+	};
+	let import = if module_path.glob {
+		quote!(use #module_path::*;)
+	} else {
+		let module_path = respan(quote!(#module_path), ident.span());
+		quote_spanned!(ident.span() => use #module_path as _;)
+	};
+	let function_name = load_fn.unwrap_or_else(|| {
+		let name = format!("{}load_{}", module_path.prefix, module_path.flat_name());
+		Ident::new(&name, ident.span())
+	});
+	let check = quote_spanned! { ident.span() =>
+		#(#custom_attrs)*
+		{
+			#vis_check
+			#(#load_attrs)*
+			#[allow(deprecated, clippy::type_complexity, clippy::multiple_bound_locations)]
+			fn #function_name() {
+				// Unlike the top level, this is spanned to the module, so an empty one would be linted.
+				#[allow(unused_imports)]
+				#import
+				#(#items)*
+				#object_check
+				#sealed_check
+			}
+			#function_name();
+		}
+	};
+
+	if pathed_attrs.is_empty() {
+		check
+	} else {
+		// Much like the mod decls, the checks are generated once for each pathed attr.
+		pathed_attrs.iter()
+			.map(|(attr, _path)| quote! {
+				#attr
+				#check
+			})
+			.collect()
+	}
+}
+
+/// Generates a test for each of the methods marked with `#[smoke_test]`, which calls it with the default value of each of its parameters.
+/// A test isn't run if it's declared inside of a function, so these are generated next to the load function, and gated the same way.
+fn tokenise_smoke_tests(module_path: &ModulePath, gate: &TokenStream, body: &[(Visibility, DeclItem)]) -> Vec<TokenStream> {
+	let mut tests = vec![];
+	for (_vis, item) in body {
+		match item {
+			DeclItem::Method(method_item) => tests.extend(smoke_test(module_path, gate, None, method_item)),
+			DeclItem::AssocFn(assoc_item) => tests.extend(smoke_test(&module_path.join(&assoc_item.type_name), gate, None, &assoc_item.method_item)),
+			DeclItem::Type(type_item) => {
+				if let TypeDeclBody::Content((_brace, ref items)) = type_item.body {
+					let cfg_attrs = type_item.attrs.iter()
+						.filter(|attr| is_attr(attr, "cfg"));
+					let gate = quote! {
+						#gate
+						#(#cfg_attrs)*
+					};
+					for item in items {
+						if let TypeItem::Method(method_item) = item {
+							tests.extend(smoke_test(module_path, &gate, Some(type_item), method_item));
+						}
+					}
+				}
+			}
+			DeclItem::Module(module_item) => {
+				if let ModuleBody::Content((_brace, ref body)) = module_item.body {
+					let module_gate = smoke_gate(&module_item.attrs);
+					let gate = quote! {
+						#gate
+						#module_gate
+					};
+					tests.extend(tokenise_smoke_tests(&module_path.join(&module_item.ident), &gate, body));
+				}
+			}
+			// Everything else isn't a function, so there's nothing to call.
+			_ => {}
+		}
+	}
+	tests
+}
+
+/// The cfgs that gate a nested module, much like the top level, except that the attributes haven't been grouped yet.
+fn smoke_gate(attrs: &[(Attribute, Option<PathValue>)]) -> TokenStream {
+	let pathed_attrs: Vec<Attribute> = attrs.iter()
+		.filter_map(|(attr, path)| if path.is_some() {
+			Some(attr.clone())
+		} else {
+			platform_shorthand(attr).map(|(attr, _path)| attr)
+		})
+		.collect();
+	let pathed_cfg = if pathed_attrs.is_empty() {
+		None
+	} else {
+		pathed_attrs.iter()
+			.map(cfg_predicate)
+			.collect::<Option<Vec<_>>>()
+			.map(|predicates| quote!(#[cfg(any(#(#predicates),*))]))
+	};
+	let cfg_attrs = attrs.iter()
+		.filter(|(attr, path)| path.is_none() && is_attr(attr, "cfg"))
+		.map(|(attr, _path)| attr);
+	quote! {
+		#pathed_cfg
+		#(#cfg_attrs)*
+	}
+}
+
+/// Generates the test for a single method, if it was marked with `#[smoke_test]`, and it can be called without anything else.
+fn smoke_test(module_path: &ModulePath, gate: &TokenStream, type_item: Option<&TypeDecl>, method_item: &TraitItemMethod) -> Option<TokenStream> {
+	let attr = method_item.attrs.iter()
+		.find(|attr| is_attr(attr, "smoke_test"))?;
+	let sig = &method_item.sig;
+	let has_receiver = sig.decl.inputs
+		.iter()
+		.any(is_receiver);
+	let impl_params = sig.decl.inputs
+		.iter()
+		.any(|arg| contains_impl_trait(arg.into_token_stream()));
+	let generic = !sig.decl.generics.params.is_empty()
+		|| type_item.is_some_and(|type_item| !type_item.generics.params.is_empty());
+	if has_receiver || impl_params || generic || sig.asyncness.is_some() || sig.unsafety.is_some() || sig.decl.variadic.is_some() {
+		attr.span()
+			.error("Only a method that can be called with the default value of each of its parameters can be smoke tested. [Hint: It can't have a receiver, generics or `impl Trait`, and can't be async, unsafe or variadic.]")
+			.emit();
+		return None;
+	}
+
+	let ident = &sig.ident;
+	let (test_name, path) = if let Some(type_item) = type_item {
+		let type_name = &type_item.ident;
+		(format!("{}smoke_{}_{}_{}", module_path.prefix, module_path.flat_name(), type_name, ident), quote!(#module_path::#type_name))
+	} else {
+		(format!("{}smoke_{}_{}", module_path.prefix, module_path.flat_name(), ident), quote!(#module_path))
+	};
+	let test_name = Ident::new(&test_name, ident.span());
+	let args = sig.decl.inputs
+		.iter()
+		.map(|_| quote!(::std::default::Default::default()));
+	let cfg_attrs = method_item.attrs.iter()
+		.filter(|attr| is_attr(attr, "cfg"));
+	Some(quote_spanned! { attr.span() =>
+		#gate
+		#(#cfg_attrs)*
+		#[test]
+		#[allow(non_snake_case)]
+		fn #test_name() {
+			let _ = #path::#ident(#(#args),*);
+		}
+	})
+}
+
+/// Checks that the module doesn't export any of the sealed names, as there's no way to list everything that it does export.
+/// Each name is imported from the module, as well as from a decoy with the same name, so if the module has it, using it is ambiguous.
+fn tokenise_sealed(module_path: &ModulePath, names: &[Ident]) -> TokenStream {
+	let sealed = module_path.prefixed("sealed", Span::call_site());
+	let decoys = module_path.prefixed("decoys", Span::call_site());
+	let check = module_path.prefixed("check", Span::call_site());
+	let module_path = module_path.relative_to_child();
+	let uses = names.iter()
+		.map(|name| quote_spanned! { name.span() =>
+			let _: #name = #name;
+		});
+	quote! {
+		#[allow(unused_imports, non_camel_case_types, dead_code)]
+		mod #sealed {
+			use #module_path::*;
+			use self::#decoys::*;
+			mod #decoys {
+				#(pub struct #names;)*
+			}
+			fn #check() {
+				#(#uses)*
+			}
+		}
+	}
+}
+
+/// Imports the item from the module, which is spanned to its declaration, so a missing item is reported there instead of on the whole macro.
+fn tokenise_import(module_path: &ModulePath, ident: &Ident) -> TokenStream {
+	// Inside of the braces, the error is only spanned to the name, rather than from the path's call site.
+	// As it's spanned to the declaration, a type that's only declared would be linted.
+	quote_spanned! { ident.span() =>
+		#[allow(unused_imports)]
+		use #module_path::{#ident};
+	}
+}
+
+/// Re-exports the item with its declared visibility, which fails if the item was declared with a more restrictive one.
+/// This only checks the item itself, so a `pub` item inside of a private module will still pass.
+/// A restriction to a module that's under the invocation, such as `pub(super)` in a nested module, can't be checked from here.
+fn tokenise_visibility(module_path: &ModulePath, vis: &Visibility, ident: &Ident, attrs: &[Attribute]) -> TokenStream {
+	if let Visibility::Inherited = vis {
+		return TokenStream::new();
+	}
+	let vis = match module_path.child_visibility(vis) {
+		Some(vis) => vis,
+		None => return TokenStream::new(),
+	};
+	let check_vis = module_path.prefixed("check_vis", ident.span());
+	let module_path = module_path.relative_to_child();
+	let cfg_attrs = attrs.iter()
+		.filter(|attr| is_attr(attr, "cfg"));
+	// The re-export needs to be in a module, as a function doesn't have any visibility to speak of.
+	quote_spanned! { ident.span() =>
+		#(#cfg_attrs)*
+		{
+			#[allow(unused_imports)]
+			mod #check_vis {
+				#vis use #module_path::#ident;
+			}
+		}
+	}
+}
+
+/// Transforms an associated type into a binding that names it, which fails if the type can't be found.
+fn tokenise_assoc_type(self_type: &SelfType, assoc_item: AssocTypeDecl) -> TokenStream {
+	let self_ty = &self_type.ty;
+	let attrs = &check_attrs(&assoc_item.attrs);
+	let leading_colon = &assoc_item.path.leading_colon;
+	let segments: Vec<&PathSegment> = assoc_item.path.segments.iter().collect();
+	let (assoc_name, trait_segments) = segments.split_last()
+		.expect("Should never happen [assoc-type]");
+	let ty = if trait_segments.is_empty() {
+		quote! {
+			#self_ty::#assoc_name
+		}
+	} else {
+		quote! {
+			<#self_ty as #leading_colon #(#trait_segments)::*>::#assoc_name
+		}
+	};
+	quote_spanned! { assoc_item.path.span() =>
+		#(#attrs)*
+		let _: Option<#ty> = None;
+	}
+}
+
+/// Transforms a field into a closure that borrows it from the type, so both the field's visibility and type are checked.
+fn tokenise_field(self_type: &SelfType, field_item: FieldDecl) -> TokenStream {
+	let attrs = &check_attrs(&field_item.attrs);
+	let ident = &field_item.ident;
+	let self_ty = &self_type.ty;
+	let ty = replace_idents(field_item.ty.into_token_stream(), &replace_self(self_ty));
+	quote_spanned! { ident.span() =>
+		#(#attrs)*
+		let _ = |_self: &#self_ty| {
+			let _: &#ty = &_self.#ident;
+		};
+	}
+}
+
+/// Transforms a constructor into the type's name, used as a value of the declared type.
+/// A struct with named fields can't be used as a value, so it'll fail, as will one with a field that isn't visible.
+fn tokenise_constructor(self_type: &SelfType, constructor_item: ConstructorDecl) -> TokenStream {
+	let attrs = &check_attrs(&constructor_item.attrs);
+	let span = constructor_item.span;
+	let self_ty = &self_type.ty;
+	// The name is spanned to the declaration, so the error doesn't point at the type's name instead.
+	let name = respan(self_type.name.clone(), span);
+	let ty = match constructor_item.fields {
+		Some(fields) => {
+			let fields = fields.into_iter()
+				.map(|ty| replace_idents(ty.into_token_stream(), &replace_self(self_ty)));
+			quote!(fn(#(#fields),*) -> #self_ty)
+		}
+		None => quote!(#self_ty),
+	};
+	// The same goes for the type, which would otherwise span from the type's name.
+	let ty = respan(ty, span);
+	quote_spanned! { span =>
+		#(#attrs)*
+		let _: #ty = #name;
+	}
+}
+
+/// The traits that an operator can be declared with, along with the name of the method that each of them is checked by.
+const OP_TRAITS: &[(&str, &str)] = &[
+	("Add", "add"), ("Sub", "sub"), ("Mul", "mul"), ("Div", "div"), ("Rem", "rem"),
+	("BitAnd", "bitand"), ("BitOr", "bitor"), ("BitXor", "bitxor"), ("Shl", "shl"), ("Shr", "shr"),
+	("AddAssign", "add_assign"), ("SubAssign", "sub_assign"), ("MulAssign", "mul_assign"), ("DivAssign", "div_assign"), ("RemAssign", "rem_assign"),
+	("BitAndAssign", "bitand_assign"), ("BitOrAssign", "bitor_assign"), ("BitXorAssign", "bitxor_assign"), ("ShlAssign", "shl_assign"), ("ShrAssign", "shr_assign"),
+	("Neg", "neg"), ("Not", "not"),
+	("Index", "index"), ("IndexMut", "index_mut"),
+	("Deref", "deref"), ("DerefMut", "deref_mut"),
+];
+
+/// Transforms an operator into the trait's method, assigned to a pointer of the declared signature.
+/// The receiver is written the same way as a method's, so `&self` is turned into `&Self`, and then `Self` is replaced with the type.
+fn tokenise_op(self_type: &SelfType, op_item: OpDecl) -> TokenStream {
+	let attrs = &check_attrs(&op_item.attrs);
+	let ident = &op_item.ident;
+	let method = match OP_TRAITS.iter().find(|(name, _method)| ident == name) {
+		Some((_name, method)) => Ident::new(method, ident.span()),
+		None => {
+			ident.span()
+				.error(format!("`{}` isn't one of the operator traits. [Hint: It has to be from `std::ops`, like `Add` or `Index`.]", ident))
+				.emit();
+			return TokenStream::new();
+		}
+	};
+	let inputs = op_item.inputs.iter()
+		.map(|arg| match arg {
+			FnArg::SelfRef(ArgSelfRef { and_token, lifetime, mutability, .. }) => quote!(#and_token #lifetime #mutability Self),
+			FnArg::SelfValue(_) => quote!(Self),
+			FnArg::Captured(ArgCaptured { ty, .. }) | FnArg::Ignored(ty) => ty.into_token_stream(),
+			FnArg::Inferred(pat) => {
+				pat.span()
+					.error("An operator's parameter needs a type. [Hint: Declare it by its type alone, like `op Add(self, Self) -> Self;`.]")
+					.emit();
+				quote!(_)
+			}
+		})
+		.collect::<Vec<_>>();
+	// The right-hand side is the trait's parameter, which defaults to `Self`, so it's taken from the declaration instead.
+	let args = match op_item.args {
+		Some(ref args) => Some(args.into_token_stream()),
+		None => inputs.get(1).map(|rhs| quote!(<#rhs>)),
+	};
+	let output = &op_item.output;
+	let self_ty = &self_type.ty;
+	let pointer = replace_idents(quote!(fn(#(#inputs),*) #output), &replace_self(self_ty));
+	let args = args.map(|args| replace_idents(args, &replace_self(self_ty)));
+	let value = respan(quote!(<#self_ty as ::core::ops::#ident #args>::#method), ident.span());
+	quote_spanned! { ident.span() =>
+		#(#attrs)*
+		let _: #pointer = #value;
+	}
+}
+
+/// Transforms a layout into a static assertion for each of its entries, as `#[repr]` itself can't be checked.
+/// A generic type's layout depends on its parameters, which a constant can't name, so it's an error.
+fn tokenise_layout(self_type: &SelfType, layout_item: LayoutDecl) -> TokenStream {
+	let attrs = &check_attrs(&layout_item.attrs);
+	let self_ty = &self_type.ty;
+	let checks = layout_item.entries.into_iter()
+		.filter_map(|LayoutEntry { key, value }| {
+			if self_type.generics.is_some() {
+				key.span()
+					.error("The layout of a generic type can't be checked, as it depends on its parameters. [Hint: Remove it, and check the layout where the parameters are known.]")
+					.emit();
+				return None;
+			}
+			let function = if key == "size" {
+				quote!(size_of)
+			} else {
+				quote!(align_of)
+			};
+			let message = format!("`{}` doesn't have the declared {} of {}", self_type.ident, key, value.clone().into_token_stream());
+			Some(quote_spanned! { value.span() =>
+				const _: () = assert!(::core::mem::#function::<#self_ty>() == #value, #message);
+			})
+		})
+		.collect::<Vec<_>>();
+	quote! {
+		#(#attrs)*
+		{
+			#(#checks)*
+		}
+	}
+}
+
+/// Transforms an enum into a match, with an arm for each of the declared variants.
+/// The fields are bound and then assigned to their declared types, so a retyped field will fail too.
+fn tokenise_enum(module_path: &ModulePath, enum_item: EnumDecl) -> TokenStream {
+	let enum_name = &enum_item.ident;
+	let arms: Vec<TokenStream> = enum_item.variants.iter()
+		.map(|variant| {
+			if let Some((_eq, ref discriminant)) = variant.discriminant {
+				discriminant.span()
+					.error("A discriminant can't be checked.")
+					.emit();
+			}
+			let attrs = &check_attrs(&variant.attrs);
+			let variant_name = &variant.ident;
+			match variant.fields {
+				Fields::Named(ref fields) => {
+					let names: Vec<&Ident> = fields.named.iter()
+						.filter_map(|field| field.ident.as_ref())
+						.collect();
+					let types = fields.named.iter().map(|field| &field.ty);
+					let bindings = names.clone();
+					quote! {
+						#(#attrs)*
+						Some(#enum_name::#variant_name { #(#names),* }) => {
+							#(let _: #types = #bindings;)*
+						}
+					}
+				}
+				Fields::Unnamed(ref fields) => {
+					let names: Vec<Ident> = (0..fields.unnamed.len())
+						.map(|i| Ident::new(&format!("_{}", i), variant_name.span()))
+						.collect();
+					let types = fields.unnamed.iter().map(|field| &field.ty);
+					let bindings = names.clone();
+					quote! {
+						#(#attrs)*
+						Some(#enum_name::#variant_name(#(#names),*)) => {
+							#(let _: #types = #bindings;)*
+						}
+					}
+				}
+				Fields::Unit => quote! {
+					#(#attrs)*
+					Some(#enum_name::#variant_name) => {}
+				},
+			}
+		})
+		.collect();
+
+	let attrs = &check_attrs(&enum_item.attrs);
+	let import = tokenise_import(module_path, enum_name);
+	quote! {
+		#(#attrs)*
+		{
+			#import
+			#[allow(unreachable_patterns)]
+			match None::<#enum_name> {
+				#(#arms)*
+				Some(_) => {}
+				None => {}
+			}
+		}
+	}
+}
+
+/// Transforms a union into a closure that reads each of the declared fields, which is unsafe, so it's done inside of an `unsafe` block.
+/// Each field is also used to build the union on its own, which a struct won't allow, unless that's the only field it has.
+fn tokenise_union(module_path: &ModulePath, union_item: UnionDecl) -> TokenStream {
+	let union_name = &union_item.ident;
+	let fields: Vec<TokenStream> = union_item.fields.named.iter()
+		.filter_map(|field| {
+			let attrs = &check_attrs(&field.attrs);
+			let ident = field.ident.as_ref()?;
+			let ty = &field.ty;
+			Some(quote_spanned! { ident.span() =>
+				#(#attrs)*
+				let _: &#ty = &_self.#ident;
+				#(#attrs)*
+				let _ = |_value: #ty| #union_name { #ident: _value };
+			})
+		})
+		.collect();
+	let attrs = &check_attrs(&union_item.attrs);
+	let import = tokenise_import(module_path, union_name);
+	let unsafe_token = Token![unsafe](union_item.union_token.span);
+	quote! {
+		#(#attrs)*
+		{
+			#import
+			let _ = |_self: &#union_name| #unsafe_token {
+				#(#fields)*
+			};
+		}
+	}
+}
+
+fn convert_method(module_path: &ModulePath, self_type: Option<&SelfType>, index: u32, method_item: TraitItemMethod) -> TokenStream {
+	let type_name = self_type.map(|self_type| self_type.ident);
+	let type_path = self_type.map(|self_type| &self_type.name);
+	let outer_generics = self_type.and_then(|self_type| self_type.generics);
+	let ident_mapping = self_type.map(|self_type| replace_self(&self_type.ty));
+
+//	println!("Context: {}", context);
+//	println!("Sig: {:?}", sig);
+//	pub struct MethodSig {
+//		pub constness: Option<Token![const]>,
+//		pub unsafety: Option<Token![unsafe]>,
+//		pub abi: Option<Abi>,
+//		pub ident: Ident,
+//		pub decl: FnDecl,
+//	}
+//	pub struct FnDecl {
+//		pub fn_token: Token![fn],
+//		pub generics: Generics,
+//		pub paren_token: token::Paren,
+//		pub inputs: Punctuated<FnArg, Token![,]>,
+//		pub variadic: Option<Token![...]>,
+//		pub output: ReturnType,
+//	}
+//	pub enum FnArg {
+//		/// Self captured by reference in a function signature: `&self` or `&mut
+//		/// self`.
+//		///
+//		/// *This type is available if Syn is built with the `"full"` feature.*
+//		pub SelfRef(ArgSelfRef {
+//			pub and_token: Token![&],
+//			pub lifetime: Option<Lifetime>,
+//			pub mutability: Option<Token![mut]>,
+//			pub self_token: Token![self],
+//		}),
+//	
+//		/// Self captured by value in a function signature: `self` or `mut
+//		/// self`.
+//		///
+//		/// *This type is available if Syn is built with the `"full"` feature.*
+//		pub SelfValue(ArgSelf {
+//			pub mutability: Option<Token![mut]>,
+//			pub self_token: Token![self],
+//		}),
+//	
+//		/// An explicitly typed pattern captured by a function signature.
+//		///
+//		/// *This type is available if Syn is built with the `"full"` feature.*
+//		pub Captured(ArgCaptured {
+//			pub pat: Pat,
+//			pub colon_token: Token![:],
+//			pub ty: Type,
+//		}),
+//	
+//		/// A pattern whose type is inferred captured by a function signature.
+//		pub Inferred(Pat),
+//		/// A type not bound to any pattern in a function signature.
+//		pub Ignored(Type),
+//	}
+//	pub struct Generics {
+//		pub lt_token: Option<Token![<]>,
+//		pub params: Punctuated<GenericParam, Token![,]>,
+//		pub gt_token: Option<Token![>]>,
+//		pub where_clause: Option<WhereClause>,
+//	}
+	lint_defaults(&method_item.sig.decl.generics);
+	let wildcard = has_wildcard(&method_item.sig.decl);
+	let sig_span = method_item.sig.span();
+	let MethodSig {
+		constness,
+		asyncness,
+		unsafety,
+		abi,
+		ident,
+		decl,
+	} = method_item.sig;
+
+	let FnDecl {
+		fn_token,
+		generics,
+		paren_token,
+		inputs,
+		variadic,
+		output,
+	} = decl;
+
+	// The bounds can name `Self` too, which isn't available inside of the generated functions.
+	let generics = if let Some(ref func) = ident_mapping {
+		replace_generics(generics, func)
+	} else {
+		generics
+	};
+
+	let has_receiver = inputs.iter()
+		.next()
+		.is_some_and(is_receiver);
+
+	let inputs = {
+		let mut values = Punctuated::new();
+		for arg in inputs {
+			let bare_fn_arg = match arg {
+				FnArg::SelfRef(ArgSelfRef{
+					and_token,
+					lifetime,
+					mutability,
+					self_token: _
+				}) => {
+					let ts = quote! {
+						_self: #and_token #lifetime #mutability Self
+					};
+					let ts = if let Some(ref func) = ident_mapping {
+						replace_idents(ts, func)
+					} else {
+						ts
+					};
+					parse2::<BareFnArg>(ts).expect("Should never happen [self-ref]")
+				}
+				FnArg::SelfValue(ArgSelf {
+					mutability,
+					self_token: _,
+				}) => {
+					let ts = quote! {
+						_self: #mutability Self
+					};
+					let ts = if let Some(ref func) = ident_mapping {
+						replace_idents(ts, func)
+					} else {
+						ts
+					};
+					parse2::<BareFnArg>(ts).expect("Should never happen [self-value]")
+				}
+				FnArg::Captured(ArgCaptured {
+					pat,
+					colon_token,
+					ty,
+				}) => {
+					let ts = ty.into_token_stream();
+					let ty = if let Some(ref func) = ident_mapping {
+						replace_idents(ts, func)
+					} else {
+						ts
+					};
+					// A receiver with a type, like `self: Box<Self>`, is named the same as the others.
+					let pat = match pat {
+						Pat::Ident(ref pat) if pat.ident == "self" => quote!(_self),
+						pat => pat.into_token_stream(),
+					};
+					let t: TokenStream = quote! {
+						#pat #colon_token #ty
+					};
+					parse2::<BareFnArg>(t).expect("Should never happen [arg-captured]")
+				}
+				FnArg::Inferred(pat) => {
+					let t = quote! {
+						#pat: _
+					};
+					parse2::<BareFnArg>(t).expect("Should never happen [inferred]")
+				}
+				FnArg::Ignored(Type::Infer(infer)) => {
+					// A lone `_` is the wildcard form, which is left for the compiler to infer, so it's the same as a pattern without a type.
+					let t = quote! {
+						_: #infer
+					};
+					parse2::<BareFnArg>(t).expect("Should never happen [wildcard]")
+				}
+				FnArg::Ignored(ty) => {
+					// This way of writing signatures has been deprecated, and I should probably emit a warning.
+					ident.span()
+						.warning("Declaring parameters without a name is deprecated, and will not be supported in the future. [Hint: Just add \"_:\" to the parameter to remove this warning...]")
+						.emit();
+					let ts = ty.into_token_stream();
+					let ts = if let Some(ref func) = ident_mapping {
+						replace_idents(ts, func)
+					} else {
+						ts
+					};
+					parse2::<BareFnArg>(ts).expect("Should never happen [ignored]")
+				}
+			};
+			values.push(bare_fn_arg);
+		}
+		values
+	};
+
+	let output = {
+		let ts = output.into_token_stream();
+		let ts = if let Some(ref func) = ident_mapping {
+			replace_idents(ts, func)
+		} else {
+			ts
+		};
+		parse2::<ReturnType>(ts).expect("Should never happen [return-type]")
+	};
+
+	let type_bare_fn = TypeBareFn {
+		unsafety,
+		abi,
+		fn_token,
+		lifetimes: None,
+		paren_token,
+		inputs,
+		variadic,
+		output,
+	};
+
+//	TypeBareFn {
+//		=pub unsafety: Option<Token![unsafe]>,
+//		=pub abi: Option<Abi>,
+//		=pub fn_token: Token![fn],
+//		~pub lifetimes: Option<BoundLifetimes>,
+//		=pub paren_token: token::Paren,
+//		~pub inputs: Punctuated<BareFnArg, Token![,]>,
+//		=pub variadic: Option<Token![...]>,
+//		=pub output: ReturnType,
+//	}
+//	pub struct BareFnArg {
+//		pub name: Option<(BareFnArgName, Token![:])>,
+//		pub ty: Type,
+//	}
+//	pub enum BareFnArgName {
+//		/// Argument given a name.
+//		Named(Ident),
+//		/// Argument not given a name, matched with `_`.
+//		Wild(Token![_]),
+//	}
+	let mut attrs = method_item.attrs;
+	let error = take_attr(&mut attrs, "error")
+		.and_then(|attr| error_message(&attr));
+	let check_names = take_attr(&mut attrs, "check_names");
+	let strict_lifetimes = take_attr(&mut attrs, "strict_lifetimes");
+	// The test is generated separately, so it's only needed there.
+	take_attr(&mut attrs, "smoke_test");
+	// It's still left out of the other checks, as it only describes the method, but whether the method has one too can be checked.
+	let must_use = attrs.iter()
+		.find(|attr| is_attr(attr, "must_use"))
+		.cloned();
+	let attrs = &check_attrs(&attrs);
+	let load_ident = {
+		let name = format!("{}ASSERT_METHOD_{}", module_path.prefix, index);
+		Ident::new(&name, ident.span())
+	};
+	// The module's path is spanned to the call site, and the type's name to where it was declared,
+	// so a mismatch would point at the whole macro, or span from the type, instead of the method's declaration.
+	let context = match type_path {
+		Some(type_path) => respan(type_path.clone(), sig_span),
+		None => respan(module_path.into_token_stream(), sig_span),
+	};
+
+	// The checks that have to call the method do so from a function that takes the same parameters.
+	let call_generics = if let Some(outer_generics) = outer_generics {
+		merge_generics(outer_generics, generics.clone())
+	} else {
+		generics.clone()
+	};
+	let (impl_generics, _ty_generics, where_clause) = call_generics.split_for_impl();
+	let params: Vec<Ident> = (0..type_bare_fn.inputs.len())
+		.map(|i| Ident::new(&format!("_arg{}", i), ident.span()))
+		.collect();
+	let params = &params;
+	let param_types = &type_bare_fn.inputs.iter()
+		.map(|arg| &arg.ty)
+		.collect::<Vec<_>>();
+	// A pointer can't have `impl Trait` anywhere in its signature, but a function can, so those are checked through one instead.
+	let impl_params = type_bare_fn.inputs.iter()
+		.any(|arg| contains_impl_trait(arg.ty.clone().into_token_stream()));
+	let impl_output = contains_impl_trait(type_bare_fn.output.clone().into_token_stream());
+
+	// A method's `where` clause can only add to the bounds of the type's parameters, like `where T: Default`, which needs a function that declares them.
+	// Without any parameters, the bounds are on types that are already known, such as `Self: Sized`, so they're left out, as they're either true or the method wouldn't exist.
+	let bounded = generics.where_clause.as_ref()
+		.is_some_and(|where_clause| !where_clause.predicates.is_empty());
+	// Lifetimes on their own can be bound by the pointer instead, `for<'a> fn(&'a u8) -> &'a u8`, so they don't need a function to declare them.
+	// One with a bound, like `'a: 'b`, can't be written there, so it still goes through a function, along with anything that has a type or const parameter.
+	let late_bound = !generics.params.is_empty() && !bounded && outer_generics.is_none() && generics.params.iter()
+		.all(|param| matches!(param, GenericParam::Lifetime(def) if def.bounds.is_empty()));
+	let generic = (!generics.params.is_empty() && !late_bound) || (bounded && outer_generics.is_some());
+	let pointer = if late_bound {
+		TypeBareFn {
+			lifetimes: Some(BoundLifetimes {
+				for_token: Default::default(),
+				lt_token: Default::default(),
+				lifetimes: generics.lifetimes().cloned().collect(),
+				gt_token: Default::default(),
+			}),
+			..type_bare_fn.clone()
+		}
+	} else {
+		type_bare_fn.clone()
+	};
+
+	// A wildcard can only be inferred by a pointer, so the checks that go through a function of their own can't be generated.
+	if wildcard {
+		let wrapped = constness.map(|const_token| const_token.span())
+			.or_else(|| asyncness.map(|async_token| async_token.span()))
+			.or_else(|| check_names.as_ref().map(|attr| attr.span()))
+			.or_else(|| if impl_params || impl_output { Some(ident.span()) } else { None });
+		if let Some(span) = wrapped {
+			span.error("A `_` can't be used in a const or async method, one with `impl Trait`, or one with #[check_names], as each type has to be named. [Hint: Replace the `_` with the type.]")
+				.emit();
+			return TokenStream::new();
+		}
+	}
+
+	// A call would happily coerce a `&mut self` into a `&self`, which is all that the wrappers below have to go on.
+	// So the receiver is passed as a value whose type is only known from the call, which is then checked to be exactly the declared one.
+	let (receiver_check, call_args) = if has_receiver {
+		let receiver_ty = param_types[0];
+		let receiver = module_path.prefixed("receiver", Span::call_site());
+		let infer = module_path.prefixed("infer", Span::call_site());
+		let is_trait = module_path.prefixed("Is", Span::call_site());
+		let assert_is = module_path.prefixed("assert_is", Span::call_site());
+		let receiver_check = quote! {
+			let #receiver = {
+				// It's never called, so it doesn't have to return, and it mustn't trip any lints against panicking.
+				#[allow(clippy::empty_loop)]
+				fn #infer<X>() -> X {
+					loop {}
+				}
+				#infer()
+			};
+			{
+				#[diagnostic::on_unimplemented(message = "The receiver is `{Self}`, but it was declared as `{X}`")]
+				trait #is_trait<X> {}
+				impl<X> #is_trait<X> for X {}
+				fn #assert_is<X: #is_trait<Y>, Y>(_: &X) {}
+				#assert_is::<_, #receiver_ty>(&#receiver);
+			}
+		};
+		let call_args: Vec<TokenStream> = Some(quote!(#receiver)).into_iter()
+			.chain(params[1..].iter().map(|param| param.into_token_stream()))
+			.collect();
+		(Some(receiver_check), call_args)
+	} else {
+		(None, params.iter().map(|param| param.into_token_stream()).collect())
+	};
+	let call_args = &call_args;
+
+	// A const fn can't call one that isn't, so this is enough to fail the check, even though it's never evaluated.
+	// It does mean that each of the parameters has to be allowed in a const fn.
+	let const_check = constness.map(|const_token| {
+		let const_fn_name = {
+			let name = format!("{}assert_const_{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let unsafety = &type_bare_fn.unsafety;
+		let output = &type_bare_fn.output;
+		quote_spanned! { const_token.span() =>
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			#const_token #unsafety fn #const_fn_name #impl_generics(#(#params: #param_types),*) #output #where_clause {
+				#context::#ident(#(#params),*)
+			}
+		}
+	});
+
+	// A safe fn would happily coerce into an unsafe pointer, so the pointer alone can't tell them apart.
+	// Only a safe fn implements the Fn traits though, so it'd match both impls, and the marker couldn't be inferred.
+	let unsafe_check = type_bare_fn.unsafety.map(|unsafe_token| {
+		if !call_generics.params.is_empty() {
+			unsafe_token.span()
+				.warning("The unsafety of a generic method can't be checked, so a safe one will still pass.")
+				.emit();
+			return TokenStream::new();
+		}
+		if wildcard {
+			unsafe_token.span()
+				.warning("The unsafety of a method with a `_` can't be checked, so a safe one will still pass.")
+				.emit();
+			return TokenStream::new();
+		}
+		if impl_params {
+			unsafe_token.span()
+				.warning("The unsafety of a method with `impl Trait` parameters can't be checked, so a safe one will still pass.")
+				.emit();
+			return TokenStream::new();
+		}
+		let must_be_unsafe = module_path.prefixed("MustBeUnsafe", unsafe_token.span());
+		let assert_unsafe = module_path.prefixed("assert_unsafe", unsafe_token.span());
+		quote_spanned! { unsafe_token.span() =>
+			#(#attrs)*
+			{
+				trait #must_be_unsafe<Marker> {}
+				impl<F> #must_be_unsafe<()> for F {}
+				impl<F: Fn(#(#param_types),*) -> R, R> #must_be_unsafe<(R,)> for F {}
+				fn #assert_unsafe<F: #must_be_unsafe<Marker>, Marker>(_: F) {}
+				#assert_unsafe(#context::#ident);
+			}
+		}
+	});
+
+	// The names of a function's parameters aren't part of its type, so the best that can be done is to call it through a wrapper that uses them.
+	// That way, every parameter has to be named, and they line up with the method's.
+	let names_check = check_names.and_then(|attr| {
+		let names = type_bare_fn.inputs.iter()
+			.map(|arg| match arg.name {
+				Some((BareFnArgName::Named(ref name), _)) => Some(name),
+				_ => {
+					arg.span()
+						.error("Every parameter has to be named for #[check_names]. [Hint: Replace the `_` with the name of the parameter.]")
+						.emit();
+					None
+				}
+			})
+			.collect::<Vec<_>>();
+		let names = &names.into_iter()
+			.collect::<Option<Vec<_>>>()?;
+		let names_fn_name = {
+			let name = format!("{}assert_names_{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let unsafety = &type_bare_fn.unsafety;
+		let body = if asyncness.is_some() {
+			let future = module_path.prefixed("future", Span::call_site());
+			// Without the leading `_`, the prefix wouldn't keep the future from being linted as unused.
+			quote! {
+				#[allow(unused_variables)]
+				let #future = #context::#ident(#(#names),*);
+			}
+		} else {
+			quote!(#context::#ident(#(#names),*))
+		};
+		let output = if asyncness.is_some() {
+			None
+		} else {
+			Some(&type_bare_fn.output)
+		};
+		Some(quote_spanned! { attr.span() =>
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			#unsafety fn #names_fn_name #impl_generics(#(#names: #param_types),*) #output #where_clause {
+				#body
+			}
+		})
+	});
+
+	// An attribute can't be read, but an unused result is linted as `unused_must_use` if the method is #[must_use], and as `unused_results` if it isn't.
+	// So the method is called as a statement, with only the latter denied, which relies on rustc picking one of the two lints.
+	// A result that's #[must_use] on its own, like a `Result`, is always linted as the former, so it passes either way.
+	let must_use_check = must_use.and_then(|attr| {
+		let returns_nothing = match type_bare_fn.output {
+			ReturnType::Default => true,
+			ReturnType::Type(_, ref ty) => match **ty {
+				Type::Tuple(ref tuple) => tuple.elems.is_empty(),
+				Type::Never(_) => true,
+				_ => false,
+			},
+		};
+		if returns_nothing || asyncness.is_some() || wildcard {
+			attr.span()
+				.warning("#[must_use] can only be checked on a method that returns something, and isn't async or has a `_`, so it isn't checked.")
+				.emit();
+			return None;
+		}
+		let must_use_fn_name = {
+			let name = format!("{}assert_must_use_{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let unsafety = &type_bare_fn.unsafety;
+		Some(quote_spanned! { attr.span() =>
+			#(#attrs)*
+			#[allow(non_snake_case, unused_must_use)]
+			#[deny(unused_results)]
+			#unsafety fn #must_use_fn_name #impl_generics(#(#params: #param_types),*) #where_clause {
+				#context::#ident(#(#params),*);
+			}
+		})
+	});
+
+	// A pointer accepts any function that's at least as general, so one that doesn't tie its lifetimes together still passes.
+	// So each lifetime that's used more than once is split off from one of the parameters at a time, which the method can't accept.
+	// If it does, it'd match both impls, and the marker couldn't be inferred, much like the unsafe check.
+	let strict_check = strict_lifetimes.map(|attr| {
+		let type_params = call_generics.params.iter()
+			.any(|param| !matches!(param, GenericParam::Lifetime(_)));
+		if type_bare_fn.unsafety.is_some() || asyncness.is_some() || impl_params || impl_output || wildcard || type_params {
+			attr.span()
+				.warning("The lifetimes can only be checked strictly on a safe method without type parameters, `impl Trait`, a `_`, or async, so they're checked as usual.")
+				.emit();
+			return TokenStream::new();
+		}
+		let lifetimes = &generics.lifetimes()
+			.map(|def| &def.lifetime)
+			.collect::<Vec<_>>();
+		let output = &type_bare_fn.output;
+		let named_output = named_output(module_path, output);
+		let returns = returns_trait(module_path, output);
+		let split = Lifetime::new("'_split", attr.span());
+		let strict = module_path.prefixed("Strict", attr.span());
+		let assert_strict = module_path.prefixed("assert_strict", attr.span());
+		let mut checks = vec![];
+		for lifetime in lifetimes {
+			let in_inputs: usize = param_types.iter()
+				.map(|ty| count_lifetime(ty.into_token_stream(), &lifetime.ident))
+				.sum();
+			if in_inputs + count_lifetime(output.into_token_stream(), &lifetime.ident) < 2 {
+				continue;
+			}
+			for target in 0..in_inputs {
+				let mut seen = 0;
+				let split_types: Vec<TokenStream> = param_types.iter()
+					.map(|ty| split_lifetime(ty.into_token_stream(), &lifetime.ident, &split, &mut seen, target))
+					.collect();
+				checks.push(quote_spanned! { attr.span() =>
+					{
+						#returns
+						trait #strict<#(#lifetimes,)* #split, Marker> {}
+						impl<#(#lifetimes,)* #split, F> #strict<#(#lifetimes,)* #split, ()> for F {}
+						impl<#(#lifetimes,)* #split, F: Fn(#(#split_types),*) -> #named_output> #strict<#(#lifetimes,)* #split, (u8,)> for F {}
+						fn #assert_strict<F: for<#(#lifetimes,)* #split> #strict<#(#lifetimes,)* #split, Marker>, Marker>(_: F) {}
+						#assert_strict(#context::#ident);
+					}
+				});
+			}
+		}
+		quote! {
+			#(#attrs)*
+			{
+				#(#checks)*
+			}
+		}
+	});
+
+	if let Some(ref error) = error {
+		if !generics.params.is_empty() || outer_generics.is_some() {
+			error.span()
+				.warning("A custom error isn't supported on generic methods, so it'll be ignored.")
+				.emit();
+		} else if asyncness.is_some() {
+			error.span()
+				.warning("A custom error isn't supported on async methods, so it'll be ignored.")
+				.emit();
+		} else if impl_params || impl_output {
+			error.span()
+				.warning("A custom error isn't supported on methods with `impl Trait`, so it'll be ignored.")
+				.emit();
+		} else if wildcard {
+			error.span()
+				.warning("A custom error isn't supported on methods with a `_`, so it'll be ignored.")
+				.emit();
+		}
+	}
+
+	// Only a pointer keeps the ABI, so it can't be checked when the method is called through a function instead.
+	if let Some(ref abi) = type_bare_fn.abi {
+		if asyncness.is_some() || impl_params || impl_output {
+			abi.span()
+				.warning("The ABI of an async method, or one with `impl Trait`, can't be checked, so a Rust one will still pass.")
+				.emit();
+		}
+	}
+
+	if let (Some(async_token), true) = (asyncness, impl_output) {
+		async_token.span()
+			.error("An async method can't return an `impl Trait`, as the future's output has to be named. [Hint: Declare it as `fn` that returns `impl Future<Output = impl Trait>` instead.]")
+			.emit();
+		return TokenStream::new();
+	}
+
+	let assertion = if let Some(async_token) = asyncness {
+		// An async fn returns an opaque future, so it can't be coerced into a pointer.
+		// Instead, the future it returns is passed to a function that only takes one with the same output, and the call itself checks the parameters.
+		// It's all in `core`, so it works without std, or even an allocator.
+		let async_fn_name = {
+			let name = format!("{}assert_async_{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let unsafety = &type_bare_fn.unsafety;
+		let output = named_output(module_path, &type_bare_fn.output);
+		let returns = returns_trait(module_path, &type_bare_fn.output);
+		let takes_future = module_path.prefixed("takes_future", async_token.span());
+		quote_spanned! { async_token.span() =>
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			#unsafety fn #async_fn_name #impl_generics(#(#params: #param_types),*) #where_clause {
+				#returns
+				#receiver_check
+				fn #takes_future<F: ::core::future::Future<Output = #output>>(_: F) {}
+				#takes_future(#context::#ident(#(#call_args),*));
+			}
+		}
+	} else if impl_params || impl_output {
+		// The wrapper declares the same signature, so each `impl Trait` parameter is passed along as is, and the returned value has to fit.
+		let impl_fn_name = {
+			let name = format!("{}assert_impl_{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let unsafety = &type_bare_fn.unsafety;
+		let output = &type_bare_fn.output;
+		quote_spanned! { ident.span() =>
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			#unsafety fn #impl_fn_name #impl_generics(#(#params: #param_types),*) #output #where_clause {
+				#receiver_check
+				#context::#ident(#(#call_args),*)
+			}
+		}
+	} else if let (Some(error), true, None, false) = (error, generics.params.is_empty(), outer_generics, wildcard) {
+		// The method is first coerced into a pointer of its own signature, which is then checked by a trait that's only implemented for the declared one.
+		// That way, the compiler will use the custom message when complaining about the trait.
+		// It does mean that a missing method, or one with a different number of parameters, will still get the normal error.
+		let trait_ident = {
+			let name = format!("{}AssertMethod{}", module_path.prefix, index);
+			Ident::new(&name, ident.span())
+		};
+		let inferred_fn = {
+			let unsafety = &type_bare_fn.unsafety;
+			let abi = &type_bare_fn.abi;
+			let variadic = &type_bare_fn.variadic;
+			let inputs = type_bare_fn.inputs.iter().map(|_| quote!(_));
+			let output = if let ReturnType::Default = type_bare_fn.output {
+				None
+			} else {
+				Some(quote!(-> _))
+			};
+			quote! {
+				#unsafety #abi fn(#(#inputs),* #variadic) #output
+			}
+		};
+		let assert = module_path.prefixed("assert", ident.span());
+		let method = module_path.prefixed("method", ident.span());
+		quote_spanned! { ident.span() =>
+			#(#attrs)*
+			{
+				#[diagnostic::on_unimplemented(message = #error)]
+				trait #trait_ident {}
+				impl #trait_ident for #type_bare_fn {}
+				fn #assert<F: #trait_ident>(_: F) {}
+				let #method: #inferred_fn = #context::#ident;
+				#assert(#method);
+			}
+		}
+	} else if let (false, None, false, AssertStyle::Const) = (generic, outer_generics, wildcard, module_path.assert_style) {
+		quote! {
+			#(#attrs)*
+			const #load_ident: #pointer = #context::#ident;
+		}
+	} else if !generic {
+		// We're already inside of a generic function, so a const can't reference its parameters.
+		// A const can't infer a `_` either, so the wildcard form ends up here too, as does the `let` style.
+		quote! {
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			let #load_ident: #pointer = #context::#ident;
+		}
+	} else {
+		// A nested function can't see the parameters of the function it's in, so they need to be declared again.
+		let generics = if let Some(outer_generics) = outer_generics {
+			merge_generics(outer_generics, generics)
+		} else {
+			generics
+		};
+		let nested_function_name = {
+			let name = if let Some(type_name) = type_name {
+				format!("{}load_{}_{}_{}_{}", module_path.prefix, module_path.flat_name(), type_name, ident, index)
+			} else {
+				format!("{}load_{}_{}_{}", module_path.prefix, module_path.flat_name(), ident, index)
+			};
+			Ident::new(&name, ident.span())
+		};
+		// Do note that we don't use ty_generics, as it's just the use-site, which for us is in the method's signature.
+		let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
+		quote! {
+			#(#attrs)*
+			#[allow(non_snake_case)]
+			fn #nested_function_name #impl_generics() #where_clause {
+				let #load_ident: #type_bare_fn = #context::#ident;
+			}
+		}
+	};
+
+	quote! {
+		#assertion
+		#const_check
+		#unsafe_check
+		#names_check
+		#must_use_check
+		#strict_check
+	}
+}
+
+/// The attributes that only describe the declared item, which mean nothing on a check, if they're even allowed on one.
+const DESCRIPTIVE_ATTRS: &[&str] = &["doc", "deprecated", "must_use", "inline", "cold", "track_caller", "repr"];
+
+/// The attributes that are carried over to a check, which leaves out the descriptive ones, such as doc comments.
+/// Anything else can change whether the check is there, or how it's linted, like a `cfg` or an `allow`.
+/// A `cfg_attr` is checked for the attributes it'd apply, as it's only as descriptive as they are.
+fn check_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+	attrs.iter()
+		.filter(|attr| !DESCRIPTIVE_ATTRS.iter().any(|name| is_attr(attr, name)))
+		.filter_map(|attr| if is_attr(attr, "cfg_attr") {
+			check_cfg_attr(attr)
+		} else {
+			Some(attr.clone())
+		})
+		.collect()
+}
+
+/// Leaves the descriptive attributes out of a `cfg_attr`, and the whole thing if that's all it had.
+/// One that can't be interpreted is kept as is, and left for the compiler to figure out.
+fn check_cfg_attr(attr: &Attribute) -> Option<Attribute> {
+	let nested = match attr.interpret_meta() {
+		Some(Meta::List(MetaList { nested, .. })) => nested,
+		_ => return Some(attr.clone()),
+	};
+	let mut nested = nested.into_iter();
+	let predicate = nested.next()?;
+	let attrs = nested.collect::<Vec<_>>();
+	let kept = attrs.iter()
+		.filter(|nested| match nested {
+			NestedMeta::Meta(meta) => !DESCRIPTIVE_ATTRS.iter().any(|name| meta.name() == name),
+			NestedMeta::Literal(_) => true,
+		})
+		.collect::<Vec<_>>();
+	if kept.len() == attrs.len() {
+		Some(attr.clone())
+	} else if kept.is_empty() {
+		None
+	} else {
+		Some(Attribute {
+			tts: quote!((#predicate, #(#kept),*)),
+			..attr.clone()
+		})
+	}
+}
+
+/// Removes the attribute with the given name, as it's only meant for the macro, and not the generated code.
+fn take_attr(attrs: &mut Vec<Attribute>, name: &str) -> Option<Attribute> {
+	let index = attrs.iter()
+		.position(|attr| is_attr(attr, name))?;
+	Some(attrs.remove(index))
+}
+
+fn is_attr(attr: &Attribute, name: &str) -> bool {
+	attr.path.leading_colon.is_none()
+		&& attr.path.segments.len() == 1
+		&& attr.path.segments.iter().all(|segment| segment.ident == name)
+}
+
+/// Gets the message out of an error attribute: `#[error = "plus_one must be fn(u8) -> u8"]`
+fn error_message(attr: &Attribute) -> Option<LitStr> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(message), .. })) = attr.interpret_meta() {
+		Some(message)
+	} else {
+		attr.span()
+			.error("Expected a message: #[error = \"...\"]")
+			.emit();
+		None
+	}
+}
+
+/// Gets the trait name out of an as_trait attribute: `#[as_trait(MyModApi)]`
+fn as_trait_name(attr: &Attribute) -> Option<Ident> {
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		if let (1, Some(NestedMeta::Meta(Meta::Word(ident)))) = (nested.len(), nested.into_iter().next()) {
+			return Some(ident);
+		}
+	}
+	attr.span()
+		.error("Expected a trait name: #[as_trait(MyModApi)]")
+		.emit();
+	None
+}
+
+/// Gets the name of the const out of an as_const attribute: `#[as_const(HANDLER)]`
+fn as_const_name(attr: &Attribute) -> Option<Ident> {
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		if let (1, Some(NestedMeta::Meta(Meta::Word(ident)))) = (nested.len(), nested.into_iter().next()) {
+			return Some(ident);
+		}
+	}
+	attr.span()
+		.error("Expected a const name: #[as_const(HANDLER)]")
+		.emit();
+	None
+}
+
+/// Gets the trait out of an object_safe attribute: `#[object_safe(Plugin)]`
+fn object_safe_name(attr: &Attribute) -> Option<Ident> {
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		if let (1, Some(NestedMeta::Meta(Meta::Word(ident)))) = (nested.len(), nested.into_iter().next()) {
+			return Some(ident);
+		}
+	}
+	attr.span()
+		.error("Expected a trait name: #[object_safe(Plugin)]")
+		.emit();
+	None
+}
+
+/// Gets the number of bytes out of a layout attribute: `#[size = 24]` or `#[align = 8]`
+fn layout_entry(key: &str, attr: &Attribute) -> Option<LayoutEntry> {
+	if let Some(Meta::NameValue(MetaNameValue { ident, lit: Lit::Int(value), .. })) = attr.interpret_meta() {
+		return Some(LayoutEntry {
+			key: Ident::new(key, ident.span()),
+			value: Expr::Lit(ExprLit {
+				attrs: vec![],
+				lit: Lit::Int(value),
+			}),
+		});
+	}
+	attr.span()
+		.error(format!("Expected a number of bytes: #[{} = 8]", key))
+		.emit();
+	None
+}
+
+/// Gets the predicates out of an expect_cfg attribute: `#[expect_cfg(unix, feature = "std")]`
+fn expect_cfg_predicates(attr: &Attribute) -> Option<Vec<NestedMeta>> {
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		if !nested.is_empty() {
+			return Some(nested.into_iter().collect());
+		}
+	}
+	attr.span()
+		.error("Expected the cfgs that the module is checked with: #[expect_cfg(unix, feature = \"std\")]")
+		.emit();
+	None
+}
+
+/// Gets the names out of a sealed attribute: `#[sealed(legacy_connect, DebugDump)]`
+fn sealed_names(attr: &Attribute) -> Option<Vec<Ident>> {
+	if let Some(Meta::List(MetaList { nested, .. })) = attr.interpret_meta() {
+		let names = nested.into_iter()
+			.map(|nested| match nested {
+				NestedMeta::Meta(Meta::Word(ident)) => Some(ident),
+				_ => None,
+			})
+			.collect::<Option<Vec<_>>>();
+		if let Some(names) = names {
+			if !names.is_empty() {
+				return Some(names);
+			}
+		}
+	}
+	attr.span()
+		.error("Expected the names that the module mustn't export: #[sealed(legacy_connect, DebugDump)]")
+		.emit();
+	None
+}
+
+/// Gets the variable out of a path_env attribute: `#[path_env = "MY_GEN_PATH"]`
+fn path_env_var(attr: &Attribute) -> Option<LitStr> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(var), .. })) = attr.interpret_meta() {
+		Some(var)
+	} else {
+		attr.span()
+			.error("Expected a variable: #[path_env = \"...\"]")
+			.emit();
+		None
+	}
+}
+
+/// Gets the path out of a root attribute: `#[root = "crate::external"]`
+fn root_path(attr: &Attribute) -> Option<Path> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(root), .. })) = attr.interpret_meta() {
+		match root.parse_with(Path::parse_mod_style) {
+			Ok(path) => return Some(path),
+			Err(error) => {
+				root.span()
+					.error(format!("The root isn't a valid path: {}", error))
+					.emit();
+				return None;
+			}
+		}
+	}
+	attr.span()
+		.error("Expected a path: #[root = \"crate::external\"]")
+		.emit();
+	None
+}
+
+/// Gets the name out of a load_fn attribute: `#[load_fn = "load_my_mod"]`
+fn load_fn_name(attr: &Attribute) -> Option<Ident> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(name), .. })) = attr.interpret_meta() {
+		match name.parse::<Ident>() {
+			Ok(ident) => return Some(ident),
+			Err(_) => {
+				name.span()
+					.error(format!("\"{}\" isn't a valid function name.", name.value()))
+					.emit();
+				return None;
+			}
+		}
+	}
+	attr.span()
+		.error("Expected a function name: #[load_fn = \"...\"]")
+		.emit();
+	None
+}
+
+/// Gets the style out of an assert_style attribute: `#[assert_style = "let"]`
+fn assert_style(attr: &Attribute) -> Option<AssertStyle> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(style), .. })) = attr.interpret_meta() {
+		match style.value().as_str() {
+			"const" => return Some(AssertStyle::Const),
+			"let" => return Some(AssertStyle::Let),
+			_ => {
+				style.span()
+					.error(format!("\"{}\" isn't an assert style. [Hint: Use either \"const\" or \"let\".]", style.value()))
+					.emit();
+				return None;
+			}
+		}
+	}
+	attr.span()
+		.error("Expected an assert style: #[assert_style = \"let\"]")
+		.emit();
+	None
+}
+
+/// The types that need std, which are looked for by a plain #[no_std].
+/// Most of them are in `alloc`, but a crate that's #[no_std] doesn't have that either, unless it asks for it.
+const NO_STD_TYPES: &[&str] = &[
+	"std", "String", "Vec", "Box", "Rc", "Arc", "Cow",
+	"HashMap", "HashSet", "BTreeMap", "BTreeSet", "VecDeque", "BinaryHeap", "LinkedList",
+	"Mutex", "RwLock", "PathBuf", "OsString", "CString", "File",
+];
+
+/// Gets the types out of a no_std attribute: `#[no_std]` or `#[no_std(String, Vec)]`
+/// Naming the types replaces the default ones.
+fn no_std_types(attr: &Attribute) -> Option<Vec<String>> {
+	match attr.interpret_meta() {
+		Some(Meta::Word(_)) => {
+			return Some(NO_STD_TYPES.iter()
+				.map(|name| name.to_string())
+				.collect());
+		}
+		Some(Meta::List(MetaList { nested, .. })) => {
+			let names = nested.into_iter()
+				.map(|nested| match nested {
+					NestedMeta::Meta(Meta::Word(ident)) => Some(ident.to_string()),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>();
+			if let Some(names) = names {
+				if !names.is_empty() {
+					return Some(names);
+				}
+			}
+		}
+		_ => {}
+	}
+	attr.span()
+		.error("Expected either nothing, or the types that need std: #[no_std(String, Vec)]")
+		.emit();
+	None
+}
+
+/// Gets the prefix out of a prefix attribute: `#[prefix = "__dm_"]`
+/// It's put in front of names like `ASSERT_METHOD_0`, so it has to be able to start an identifier.
+fn prefix_name(attr: &Attribute) -> Option<String> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(prefix), .. })) = attr.interpret_meta() {
+		let value = prefix.value();
+		let valid = value.chars().next().is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+			&& value.chars().all(|c| c == '_' || c.is_ascii_alphanumeric());
+		if valid {
+			return Some(value);
+		}
+		prefix.span()
+			.error(format!("\"{}\" can't start an identifier, so it can't be used as a prefix. [Hint: Use letters, digits and underscores, like \"__dm_\".]", value))
+			.emit();
+		return None;
+	}
+	attr.span()
+		.error("Expected a prefix for the generated names: #[prefix = \"__dm_\"]")
+		.emit();
+	None
+}
+
+/// Warns about every type in the signatures that needs std, if the module is #[no_std].
+/// It only goes by the name, so a type of the same name that doesn't need std is warned about too.
+fn lint_no_std(module_path: &ModulePath, ts: &TokenStream) {
+	if module_path.no_std.is_empty() {
+		return;
+	}
+	for tt in ts.clone() {
+		match tt {
+			TokenTree::Group(group) => lint_no_std(module_path, &group.stream()),
+			TokenTree::Ident(ident) => {
+				let name = ident.to_string();
+				if module_path.no_std.contains(&name) {
+					ident.span()
+						.warning(format!("The signature names `{}`, which isn't there without std, but the module is #[no_std]. [Hint: Use a type from `core` instead.]", name))
+						.emit();
+				}
+			}
+			_ => {}
+		}
+	}
+}
+
+/// The names that the prelude brings in, which a module's glob import would shadow inside of the checks.
+const PRELUDE_NAMES: &[&str] = &[
+	"Option", "Some", "None", "Result", "Ok", "Err", "Box", "String", "Vec", "ToString", "ToOwned",
+	"Clone", "Copy", "Send", "Sync", "Sized", "Unpin", "Drop", "Fn", "FnMut", "FnOnce", "drop",
+	"Default", "Eq", "PartialEq", "Ord", "PartialOrd", "AsRef", "AsMut", "Into", "From", "TryFrom", "TryInto",
+	"Iterator", "IntoIterator", "DoubleEndedIterator", "ExactSizeIterator", "Extend", "FromIterator",
+];
+
+/// Warns about a declared item that has the name of something in the prelude, as the glob import shadows it inside of the checks.
+/// The item itself is always named by its path, so it's the other signatures that would end up naming the module's instead.
+fn lint_prelude(module_path: &ModulePath, ident: &Ident) {
+	if !module_path.glob || !PRELUDE_NAMES.iter().any(|name| ident == name) {
+		return;
+	}
+	ident.span()
+		.warning(format!("`{}` is also in the prelude, and the module's glob import shadows it, so any signature that names it means the module's instead. [Hint: Use #[no_glob] if they mean the prelude's.]", ident))
+		.emit();
+}
+
+/// Gets the path out of a manifest attribute: `#![manifest = "target/def_mod.json"]`
+fn manifest_path(attr: &Attribute) -> Option<LitStr> {
+	if let Some(Meta::NameValue(MetaNameValue { lit: Lit::Str(path), .. })) = attr.interpret_meta() {
+		return Some(path);
+	}
+	attr.span()
+		.error("Expected a path: #![manifest = \"...\"]")
+		.emit();
+	None
+}
+
+/// The directory that a path is relative to, which is the one of the file that `def_mod!` was invoked from, the same as a `#[path]` would be.
+/// There's no file if the macro isn't expanded by the compiler, such as by an IDE, or if it's called outside of a proc macro altogether.
+fn source_dir(span: Span) -> Option<std::path::PathBuf> {
+	if !proc_macro::is_available() {
+		return None;
+	}
+	let file = span.unstable().local_file()?;
+	Some(file.parent().map(|dir| dir.to_path_buf()).unwrap_or_default())
+}
+
+/// Checks that the path is a file, relative to the directory of the file that `def_mod!` was invoked from.
+/// A single path and a list of candidates go by the same rule, and if there's nothing to check against, it's assumed to exist.
+fn path_exists(path: &LitStr) -> bool {
+	match source_dir(path.span()) {
+		Some(dir) => dir.join(path.value()).is_file(),
+		None => true,
+	}
+}
+
+/// Picks the first of the paths that exists.
+fn existing_path(candidates: &[LitStr]) -> Option<LitStr> {
+	let first = candidates.first()?;
+	let existing = candidates.iter()
+		.find(|candidate| path_exists(candidate));
+	if existing.is_none() {
+		let paths = candidates.iter()
+			.map(|candidate| format!("\"{}\"", candidate.value()))
+			.collect::<Vec<_>>()
+			.join(", ");
+		first.span()
+			.error(format!("None of the paths exist: {}", paths))
+			.emit();
+	}
+	existing.cloned()
+}
+
+/// Reads an environment variable, which on nightly also makes sure the macro is expanded again if it changes.
+fn env_var(var: &str) -> std::result::Result<String, std::env::VarError> {
+	#[cfg(not(feature = "stable"))]
+	{
+		if proc_macro::is_available() {
+			return proc_macro::tracked::env_var(var);
+		}
+	}
+	std::env::var(var)
+}
+
+/// Reads a path out of the environment variable, which also makes sure the macro is expanded again if the variable changes.
+fn env_path(var: &LitStr) -> Option<LitStr> {
+	match env_var(&var.value()) {
+		Ok(path) => Some(LitStr::new(&path, var.span())),
+		Err(_) => {
+			var.span()
+				.error(format!("The environment variable \"{}\" isn't set, so it can't be used as a path.", var.value()))
+				.emit();
+			None
+		}
+	}
+}
+
+/// Creates a mapping that replaces `Self` with the given type.
+fn replace_self(self_ty: &TokenStream) -> impl Fn(Ident) -> TokenStream + '_ {
+	move |ident: Ident| {
+		// The ident has to be the keyword itself, so a name that merely contains it, like `SelfRef`, is left alone.
+		if is_self_keyword(&ident) {
+			// The replacement takes over the span, so any errors still point at the original `Self`.
+			self_ty.clone()
+				.into_iter()
+				.map(|mut tt| {
+					tt.set_span(ident.span());
+					tt
+				})
+				.collect()
+		} else {
+			ident.into_token_stream()
+		}
+	}
+}
+
+/// Gives each of the tokens the same span, so anything that's generated from them points at it.
+fn respan(ts: TokenStream, span: Span) -> TokenStream {
+	ts.into_iter()
+		.map(|mut tt| {
+			tt.set_span(span);
+			tt
+		})
+		.collect()
+}
+
+/// Checks if the argument is the method's receiver, which includes one with a type: `self: Box<Self>`
+fn is_receiver(arg: &FnArg) -> bool {
+	match arg {
+		FnArg::SelfRef(_) | FnArg::SelfValue(_) => true,
+		FnArg::Captured(ArgCaptured { pat: Pat::Ident(pat), .. }) => pat.ident == "self",
+		_ => false,
+	}
+}
+
+/// Checks if the ident is the `Self` keyword, by parsing it as one.
+fn is_self_keyword(ident: &Ident) -> bool {
+	parse2::<Token![Self]>(ident.clone().into_token_stream()).is_ok()
+}
+
+/// Combines the generics of a declaration with the ones of the method inside of it.
+/// Lifetimes need to be declared before anything else, so they're moved to the front.
+fn merge_generics(outer: &Generics, inner: Generics) -> Generics {
+	let (lifetimes, others): (Vec<GenericParam>, Vec<GenericParam>) = outer.params.iter()
+		.cloned()
+		.chain(inner.params)
+		.partition(|param| matches!(param, GenericParam::Lifetime(_)));
+	let where_clause = match (outer.where_clause.clone(), inner.where_clause) {
+		(Some(mut outer), Some(inner)) => {
+			outer.predicates.extend(inner.predicates);
+			Some(outer)
+		}
+		(outer, inner) => outer.or(inner),
+	};
+	Generics {
+		lt_token: Some(Default::default()),
+		params: lifetimes.into_iter().chain(others).collect(),
+		gt_token: Some(Default::default()),
+		where_clause,
+	}
+}
+
+/// Names the type that a method returns, so it can be used outside of the return position.
+/// `!` can only be written as a return type, so it's named through a pointer that returns it instead, which needs `returns_trait`.
+fn named_output(module_path: &ModulePath, output: &ReturnType) -> TokenStream {
+	match output {
+		ReturnType::Default => quote!(()),
+		ReturnType::Type(_, ref ty) => match **ty {
+			Type::Never(_) => {
+				let returns = module_path.prefixed("Returns", Span::call_site());
+				quote!(<fn() -> ! as #returns>::Output)
+			}
+			ref ty => quote!(#ty),
+		},
+	}
+}
+
+/// Declares the trait that `named_output` uses to name `!`, but only if it's needed, as it'd be unused otherwise.
+fn returns_trait(module_path: &ModulePath, output: &ReturnType) -> Option<TokenStream> {
+	match output {
+		ReturnType::Type(_, ref ty) if matches!(**ty, Type::Never(_)) => {
+			let returns = module_path.prefixed("Returns", Span::call_site());
+			Some(quote! {
+				trait #returns {
+					type Output;
+				}
+				impl<T> #returns for fn() -> T {
+					type Output = T;
+				}
+			})
+		}
+		_ => None,
+	}
+}
+
+/// Replaces the idents of the generic parameters and the where clause, as the generics don't include the latter in their tokens.
+fn replace_generics<F>(generics: Generics, func: &F) -> Generics
+		where F: Fn(Ident) -> TokenStream {
+	let params = replace_idents(generics.params.into_token_stream(), func);
+	let params = Punctuated::parse_terminated.parse2(params)
+		.expect("Should never happen [generic-params]");
+	let where_clause = generics.where_clause.map(|where_clause| {
+		let ts = replace_idents(where_clause.into_token_stream(), func);
+		parse2::<WhereClause>(ts).expect("Should never happen [where-clause]")
+	});
+	Generics {
+		params,
+		where_clause,
+		..generics
+	}
+}
+
+/// Whether any of the parameters or the return type uses a `_`, which leaves it to be inferred from the method.
+fn has_wildcard(decl: &FnDecl) -> bool {
+	let inputs = decl.inputs.iter()
+		.any(|arg| match arg {
+			FnArg::Captured(arg) => contains_wildcard(arg.ty.clone().into_token_stream()),
+			FnArg::Ignored(ty) => contains_wildcard(ty.clone().into_token_stream()),
+			FnArg::Inferred(_) => true,
+			FnArg::SelfRef(_) | FnArg::SelfValue(_) => false,
+		});
+	inputs || contains_wildcard(decl.output.clone().into_token_stream())
+}
+
+/// Finds the first `Self` in the tokens, which is where it's reported.
+fn find_self_type(ts: TokenStream) -> Option<Span> {
+	ts.into_iter()
+		.find_map(|tt| match tt {
+			TokenTree::Group(g) => find_self_type(g.stream()),
+			TokenTree::Ident(ref i) if is_self_keyword(i) => Some(i.span()),
+			_ => None,
+		})
+}
+
+fn contains_wildcard(ts: TokenStream) -> bool {
+	let mut lifetime = false;
+	ts.into_iter()
+		.any(|tt| {
+			// The `_` of an elided lifetime, `'_`, isn't one.
+			let after_lifetime = lifetime;
+			lifetime = matches!(tt, TokenTree::Punct(ref p) if p.as_char() == '\'');
+			match tt {
+				TokenTree::Group(g) => contains_wildcard(g.stream()),
+				TokenTree::Ident(i) => i == "_" && !after_lifetime,
+				_ => false,
+			}
+		})
+}
+
+/// Counts how many times the lifetime is used: `'a`
+fn count_lifetime(ts: TokenStream, name: &Ident) -> usize {
+	let mut lifetime = false;
+	ts.into_iter()
+		.map(|tt| {
+			let after_lifetime = lifetime;
+			lifetime = matches!(tt, TokenTree::Punct(ref p) if p.as_char() == '\'');
+			match tt {
+				TokenTree::Group(g) => count_lifetime(g.stream(), name),
+				TokenTree::Ident(ref i) if after_lifetime && i == name => 1,
+				_ => 0,
+			}
+		})
+		.sum()
+}
+
+/// Replaces the one use of the lifetime that's at the target, counting from the ones that were already seen.
+fn split_lifetime(ts: TokenStream, name: &Ident, split: &Lifetime, seen: &mut usize, target: usize) -> TokenStream {
+	let mut out = TokenStream::new();
+	let mut previous: Option<TokenTree> = None;
+	for tt in ts {
+		let tt = match tt {
+			TokenTree::Group(g) => {
+				let mut group = Group::new(g.delimiter(), split_lifetime(g.stream(), name, split, seen, target));
+				group.set_span(g.span());
+				TokenTree::Group(group)
+			}
+			TokenTree::Ident(i) => {
+				let after_lifetime = matches!(previous, Some(TokenTree::Punct(ref p)) if p.as_char() == '\'');
+				if after_lifetime && i == *name {
+					*seen += 1;
+					if *seen - 1 == target {
+						// The apostrophe was already added, so it's replaced along with the name.
+						previous = None;
+						split.to_tokens(&mut out);
+						continue;
+					}
+				}
+				TokenTree::Ident(i)
+			}
+			tt => tt,
+		};
+		if let Some(previous) = previous.replace(tt) {
+			previous.to_tokens(&mut out);
+		}
+	}
+	if let Some(previous) = previous {
+		previous.to_tokens(&mut out);
+	}
+	out
+}
+
+/// Checks if there's an `impl Trait` anywhere in the tokens of a type.
+fn contains_impl_trait(ts: TokenStream) -> bool {
+	ts.into_iter()
+		.any(|tt| match tt {
+			TokenTree::Group(g) => contains_impl_trait(g.stream()),
+			TokenTree::Ident(i) => i == "impl",
+			_ => false,
+		})
+}
+
+fn replace_idents<F>(ts: TokenStream, func: &F) -> TokenStream
+		where F: Fn(Ident) -> TokenStream {
+	let mut out = TokenStream::new();
+	// The tokens of a macro call are only its input, so they're left as they are, much like a literal is: `stringify!(Self)`
+	let mut after_bang = false;
+	for tt in ts {
+		let bang = matches!(tt, TokenTree::Punct(ref punct) if punct.as_char() == '!');
+		match tt {
+			TokenTree::Group(g) if after_bang => TokenTree::Group(g).to_tokens(&mut out),
+			TokenTree::Group(g) => {
+				let delimiter = g.delimiter();
+				let ts = g.stream();
+				let ts = replace_idents(ts, func);
+				// A new group is spanned to the call site, so it needs the original's span, otherwise errors inside of it lose their place.
+				let mut group = Group::new(delimiter, ts);
+				group.set_span(g.span());
+				TokenTree::Group(group).to_tokens(&mut out);
+			},
+			TokenTree::Ident(i) => out.extend(func(i)),
+			v => v.to_tokens(&mut out),
+		}
+		after_bang = bang;
+	}
+	out
+}
+
+/// The diagnostics are emitted through `proc_macro`'s own API, which is only on nightly, and only while a proc macro is expanding.
+/// With the `stable` feature, or outside of a proc macro, they're held on to instead, and added to the output once the modules are generated.
+/// An error becomes a `compile_error!`, and a warning becomes the use of a deprecated item, which is as close as stable gets to one.
+mod diagnostic {
+	use proc_macro2::{Span, TokenStream};
+
+	pub trait Diagnose {
+		fn error<T: Into<String>>(self, message: T) -> Diagnostic;
+		fn warning<T: Into<String>>(self, message: T) -> Diagnostic;
+	}
+
+	impl Diagnose for Span {
+		fn error<T: Into<String>>(self, message: T) -> Diagnostic {
+			Diagnostic {
+				span: self,
+				level: Level::Error,
+				message: message.into(),
+			}
+		}
+
+		fn warning<T: Into<String>>(self, message: T) -> Diagnostic {
+			Diagnostic {
+				span: self,
+				level: Level::Warning,
+				message: message.into(),
+			}
+		}
+	}
+
+	enum Level {
+		Error,
+		Warning,
+	}
+
+	pub struct Diagnostic {
+		span: Span,
+		level: Level,
+		message: String,
+	}
+
+	thread_local! {
+		static EMITTED: std::cell::RefCell<Vec<Diagnostic>> = const { std::cell::RefCell::new(Vec::new()) };
+	}
+
+	impl Diagnostic {
+		pub fn emit(self) {
+			#[cfg(not(feature = "stable"))]
+			{
+				if proc_macro::is_available() {
+					let span = self.span.unstable();
+					match self.level {
+						Level::Error => span.error(self.message).emit(),
+						Level::Warning => span.warning(self.message).emit(),
+					}
+					return;
+				}
+			}
+			EMITTED.with(|emitted| emitted.borrow_mut().push(self));
+		}
+
+		fn into_tokens(self) -> TokenStream {
+			let Diagnostic {
+				span,
+				level,
+				message,
+			} = self;
+			match level {
+				Level::Error => syn::Error::new(span, message).to_compile_error(),
+				Level::Warning => {
+					let message = format!("def_mod: {}", message);
+					quote::quote_spanned! { span =>
+						const _: () = {
+							#[deprecated(note = #message)]
+							#[allow(non_upper_case_globals)]
+							const warning: () = ();
+							warning
+						};
+					}
+				}
+			}
+		}
+	}
+
+	/// The diagnostics that were held on to since the last time, which have to be added to the output.
+	/// On nightly, inside of a proc macro, they've already been emitted, so there's nothing to add.
+	pub fn take() -> TokenStream {
+		EMITTED.with(|emitted| emitted.borrow_mut().drain(..).map(Diagnostic::into_tokens).collect())
+	}
+}
+
+/// Writes out what each invocation declared as JSON, so the surface that's checked can be compared by other tools.
+#[cfg(feature = "manifest")]
+mod manifest {
+	use super::*;
+	use serde_json::{json, Map, Value};
+	use std::fs;
+
+	/// Adds the modules to the manifest, under an entry for the invocation.
+	/// The entries are keyed by where the invocations are, so they can share a file, and each one only replaces its own.
+	pub(crate) fn write(path: &LitStr, declarations: &[ModuleDecl]) {
+		// The entries are keyed by where the invocation is, which there isn't one of outside of a proc macro.
+		if !proc_macro::is_available() {
+			return;
+		}
+		let file = std::path::PathBuf::from(path.value());
+		let span = proc_macro::Span::call_site();
+		let key = format!("{}:{}", span.file(), span.line());
+		let mut manifest = fs::read_to_string(&file)
+			.ok()
+			.and_then(|contents| serde_json::from_str::<Map<String, Value>>(&contents).ok())
+			.unwrap_or_default();
+		manifest.insert(key, declarations.iter().map(module).collect());
+
+		let result = file.parent()
+			.map_or(Ok(()), fs::create_dir_all)
+			.and_then(|_| {
+				let contents = serde_json::to_string_pretty(&manifest).expect("Should never happen [manifest]");
+				fs::write(&file, contents)
+			});
+		if let Err(error) = result {
+			path.span()
+				.warning(format!("The manifest couldn't be written to \"{}\": {}", file.display(), error))
+				.emit();
+		}
+	}
+
+	fn module(module: &ModuleDecl) -> Value {
+		let attrs: Vec<Value> = module.attrs.iter()
+			.map(|(attr, path)| json!({
+				"attr": tokens(attr),
+				"path": path.as_ref().map(path_value),
+			}))
+			.collect();
+		let items = match module.body {
+			ModuleBody::Content((_brace, ref body)) => body.iter()
+				.map(|(vis, item)| decl_item(vis, item))
+				.collect(),
+			ModuleBody::Terminated(_) => Value::Null,
+		};
+		json!({
+			"kind": "mod",
+			"name": module.ident.to_string(),
+			"vis": tokens(&module.vis),
+			"attrs": attrs,
+			"items": items,
+		})
+	}
+
+	fn path_value(path: &PathValue) -> Value {
+		match path {
+			PathValue::Lit(path) => json!(path.value()),
+			PathValue::Env(var) => json!({ "env": var.value() }),
+			PathValue::Candidates(candidates) => candidates.iter()
+				.map(|candidate| json!(candidate.value()))
+				.collect(),
+		}
+	}
+
+	fn decl_item(vis: &Visibility, item: &DeclItem) -> Value {
+		let mut value = match item {
+			DeclItem::Method(method_item) => method(method_item),
+			DeclItem::AssocFn(assoc_item) => {
+				let mut value = method(&assoc_item.method_item);
+				value["type"] = json!(assoc_item.type_name.to_string());
+				value
+			}
+			DeclItem::Sig(sig_item) => json!({
+				"kind": "sig",
+				"name": sig_item.ident.to_string(),
+				"signature": tokens(&sig_item.ty),
+			}),
+			DeclItem::SigFn(sig_fn_item) => json!({
+				"kind": "fn",
+				"name": sig_fn_item.ident.to_string(),
+				"sig": sig_fn_item.sig.to_string(),
+			}),
+			DeclItem::Type(type_item) => {
+				let items = match type_item.body {
+					TypeDeclBody::Content((_brace, ref items)) => items.iter()
+						.map(type_item_value)
+						.collect(),
+					TypeDeclBody::Terminated(_) => Value::Null,
+				};
+				json!({
+					"kind": "type",
+					"name": type_item.ident.to_string(),
+					"generics": tokens(&type_item.generics),
+					"bounds": tokens(&type_item.bounds),
+					"items": items,
+				})
+			}
+			DeclItem::Trait(trait_item) => {
+				let items = match trait_item.body {
+					TraitDeclBody::Content((_brace, ref items)) => items.iter()
+						.map(|item| match item {
+							TraitDeclItem::Method(method_item) => method(method_item),
+							TraitDeclItem::Type(assoc_item) => json!({
+								"kind": "type",
+								"name": assoc_item.ident.to_string(),
+								"bounds": tokens(&assoc_item.bounds),
+							}),
+						})
+						.collect(),
+					TraitDeclBody::Terminated(_) => Value::Null,
+				};
+				json!({
+					"kind": "trait",
+					"name": trait_item.ident.to_string(),
+					"items": items,
+				})
+			}
+			DeclItem::Enum(enum_item) => json!({
+				"kind": "enum",
+				"name": enum_item.ident.to_string(),
+				"variants": enum_item.variants.iter().map(tokens).collect::<Vec<_>>(),
+			}),
+			DeclItem::Union(union_item) => json!({
+				"kind": "union",
+				"name": union_item.ident.to_string(),
+				"fields": union_item.fields.named.iter().map(tokens).collect::<Vec<_>>(),
+			}),
+			DeclItem::Const(const_item) => json!({
+				"kind": "const",
+				"name": const_item.ident.to_string(),
+				"type": tokens(&const_item.ty),
+			}),
+			DeclItem::Static(static_item) => json!({
+				"kind": if static_item.mutability.is_some() { "static mut" } else { "static" },
+				"name": static_item.ident.to_string(),
+				"type": tokens(&static_item.ty),
+			}),
+			DeclItem::Use(use_item) => json!({
+				"kind": if use_item.fn_token.is_some() { "use fn" } else { "use" },
+				"name": use_item.ident.to_string(),
+			}),
+			DeclItem::Macro(macro_item) => json!({
+				"kind": "macro",
+				"name": macro_item.ident.to_string(),
+			}),
+			DeclItem::Extern(extern_item) => json!({
+				"kind": "extern",
+				"abi": tokens(&extern_item.abi),
+				"items": extern_item.items.iter()
+					.map(|(vis, method_item)| {
+						let mut value = method(method_item);
+						value["vis"] = tokens(vis);
+						value
+					})
+					.collect::<Vec<_>>(),
+			}),
+			DeclItem::Impl(impl_item) => json!({
+				"kind": "impl",
+				"generics": tokens(&impl_item.generics),
+				"trait": tokens(&impl_item.trait_path),
+				"type": tokens(&impl_item.self_ty),
+				"items": impl_item.items.iter()
+					.map(method)
+					.collect::<Vec<_>>(),
+			}),
+			DeclItem::Alias(alias_item) => json!({
+				"kind": "alias",
+				"name": alias_item.ident.to_string(),
+				"generics": tokens(&alias_item.generics),
+				"type": tokens(&alias_item.ty),
+			}),
+			DeclItem::Module(module_item) => module(module_item),
+		};
+		// A module keeps its own, as they can declare paths.
+		if !matches!(item, DeclItem::Module(_)) {
+			value["vis"] = tokens(vis);
+			value["attrs"] = check_attrs(item.attrs()).into_iter().map(tokens).collect();
+		}
+		value
+	}
+
+	fn type_item_value(item: &TypeItem) -> Value {
+		match item {
+			TypeItem::Method(method_item) => method(method_item),
+			TypeItem::Const(const_item) => json!({
+				"kind": "const",
+				"name": const_item.ident.to_string(),
+				"type": tokens(&const_item.ty),
+			}),
+			TypeItem::Type(assoc_item) => json!({
+				"kind": "type",
+				"path": tokens(&assoc_item.path),
+			}),
+			TypeItem::Constructor(constructor_item) => json!({
+				"kind": "constructor",
+				"fields": constructor_item.fields.as_ref().map(tokens),
+			}),
+			TypeItem::Field(field_item) => json!({
+				"kind": "field",
+				"name": field_item.ident.to_string(),
+				"type": tokens(&field_item.ty),
+			}),
+			TypeItem::Op(op_item) => json!({
+				"kind": "op",
+				"trait": op_item.ident.to_string(),
+				"args": op_item.args.as_ref().map(tokens),
+				"inputs": tokens(&op_item.inputs),
+				"output": tokens(&op_item.output),
+			}),
+			TypeItem::Layout(layout_item) => {
+				let mut value = json!({
+					"kind": "layout",
+				});
+				for entry in layout_item.entries.iter() {
+					value[entry.key.to_string()] = tokens(&entry.value);
+				}
+				value
+			}
+		}
+	}
+
+	fn method(method_item: &TraitItemMethod) -> Value {
+		json!({
+			"kind": "fn",
+			"name": method_item.sig.ident.to_string(),
+			"signature": tokens(&method_item.sig),
+			"attrs": check_attrs(&method_item.attrs).into_iter().map(tokens).collect::<Vec<_>>(),
+		})
+	}
+
+	fn tokens<T: ToTokens>(value: T) -> Value {
+		Value::String(value.into_token_stream().to_string())
+	}
+}
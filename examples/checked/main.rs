@@ -0,0 +1,26 @@
+extern crate def_mod;
+
+use def_mod::def_mod_check;
+
+// The path is picked by a `cfg_attr`, which the macro can't declare, so the module is declared by hand instead.
+#[cfg_attr(unix, path = "nix.rs")]
+#[cfg_attr(not(unix), path = "other.rs")]
+mod sys;
+
+// Only the checks are generated, as if the module had `#[root = "self"]`.
+// The module's own attributes can't be read, but it can be made sure that it's checked on the targets that are expected to.
+def_mod_check! {
+	#[expect_cfg(any(unix, windows))]
+	mod sys {
+		fn name() -> &'static str;
+		type Handle {
+			tuple(i32);
+		}
+	}
+}
+
+fn main() {
+	let handle = sys::Handle(0);
+	assert_eq!(handle.0, 0);
+	assert!(!sys::name().is_empty());
+}
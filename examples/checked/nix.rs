@@ -0,0 +1,5 @@
+pub fn name() -> &'static str {
+	"unix"
+}
+
+pub struct Handle(pub i32);
@@ -0,0 +1,5 @@
+pub fn name() -> &'static str {
+	"other"
+}
+
+pub struct Handle(pub i32);
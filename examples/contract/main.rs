@@ -0,0 +1,50 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	// Along with the usual checks, this declares a trait with the same functions, which each take `&self`.
+	#[as_trait(MathApi)]
+	mod math {
+		// A signature can be given a name, so functions that mirror each other can share it.
+		sig Step = fn(u8) -> u8;
+		fn plus_one: Step;
+		fn minus_one: Step;
+		// The const has to be a pointer with the same signature, which is how the function is handed out to callbacks.
+		const fn double(_: u32) -> u32 as const DOUBLE;
+
+		type Counter {
+			// `Self` is replaced with the type, so the trait's own stays free.
+			fn new() -> Self;
+		}
+	}
+}
+
+/// A stand-in for the module, which could be used in tests.
+struct FakeMath;
+
+impl MathApi for FakeMath {
+	fn plus_one(&self, value: u8) -> u8 {
+		value + 1
+	}
+
+	fn minus_one(&self, value: u8) -> u8 {
+		value - 1
+	}
+
+	fn double(&self, value: u32) -> u32 {
+		value * 2
+	}
+
+	fn new(&self) -> math::Counter {
+		math::Counter::new()
+	}
+}
+
+fn main() {
+	let api: &dyn MathApi = &FakeMath;
+	assert_eq!(api.plus_one(1), 2);
+	assert_eq!(api.minus_one(2), 1);
+	assert_eq!(api.double(2), 4);
+	assert_eq!(api.new().get(), 0);
+}
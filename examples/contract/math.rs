@@ -0,0 +1,25 @@
+pub fn plus_one(value: u8) -> u8 {
+	value + 1
+}
+
+pub fn minus_one(value: u8) -> u8 {
+	value - 1
+}
+
+pub const fn double(value: u32) -> u32 {
+	value * 2
+}
+
+pub const DOUBLE: fn(u32) -> u32 = double;
+
+pub struct Counter(u32);
+
+impl Counter {
+	pub fn new() -> Self {
+		Counter(0)
+	}
+
+	pub fn get(&self) -> u32 {
+		self.0
+	}
+}
@@ -0,0 +1,16 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	mod facade {
+		// These are defined somewhere else, but they only have to be reachable from here.
+		use fn build;
+		use Widget;
+		// A re-exported function can still be checked for its signature.
+		fn render(_: &Widget) -> String;
+	}
+}
+
+fn main() {
+}
@@ -0,0 +1,38 @@
+use std::os::raw::{c_char, c_int};
+
+extern "C" {
+	pub fn printf(format: *const c_char, ...) -> c_int;
+	pub fn abs(value: c_int) -> c_int;
+}
+
+extern "C" {
+	pub fn strlen(value: *const c_char) -> usize;
+	pub fn atoi(value: *const c_char) -> c_int;
+}
+
+#[no_mangle]
+pub static mut LAST_ERROR: c_int = 0;
+
+#[repr(C)]
+pub struct Packet {
+	pub id: u32,
+	pub len: u16,
+	pub kind: u8,
+	pub flags: u8,
+	pub crc: u32,
+}
+
+#[repr(C)]
+pub union Reg {
+	pub word: u32,
+	pub bytes: [u8; 4],
+}
+
+pub struct Marker;
+
+#[repr(C)]
+pub struct Header {
+	pub magic: u64,
+	pub len: u64,
+	pub packet: Packet,
+}
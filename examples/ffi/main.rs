@@ -0,0 +1,56 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+use std::os::raw::{c_char, c_int};
+
+def_mod! {
+	mod ffi {
+		// A foreign function is always unsafe, and the `...` is kept in the pointer that it's checked against.
+		unsafe extern "C" fn printf(_: *const c_char, ...) -> c_int;
+		unsafe extern "C" fn abs(_: c_int) -> c_int;
+
+		// Or declared as a block, which gives each function its ABI and makes it unsafe.
+		extern "C" {
+			pub fn strlen(_: *const c_char) -> usize;
+			pub fn atoi(_: *const c_char) -> c_int;
+		}
+
+		// A static that's shared with C is usually mutable, which has to be declared, as a static that isn't can't be taken as mutable.
+		static mut LAST_ERROR: c_int;
+
+		// The representation itself can't be checked, so the layout that C expects is checked instead.
+		#[repr(C)]
+		type Packet {
+			size = 12, align = 4;
+			field crc: u32;
+		}
+
+		// The size and alignment can be given as attributes too, which works on a type that isn't declared with a body.
+		#[size = 0]
+		type Marker;
+		#[repr(C)]
+		#[size = 32]
+		#[align = 8]
+		type Header {
+			field packet: Packet;
+		}
+
+		// Reading a union's field is unsafe, so it's checked from inside of an `unsafe` block.
+		union Reg {
+			word: u32,
+			bytes: [u8; 4],
+		}
+	}
+}
+
+fn main() {
+	let value = b"42\0".as_ptr() as *const c_char;
+	unsafe {
+		assert_eq!(ffi::strlen(value), 2);
+		assert_eq!(ffi::atoi(value), 42);
+		let reg = ffi::Reg {
+			word: u32::from_ne_bytes([1, 2, 3, 4]),
+		};
+		assert_eq!(reg.bytes, [1, 2, 3, 4]);
+	}
+}
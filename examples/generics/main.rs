@@ -0,0 +1,70 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	mod wrapper {
+		// Any methods are checked with the same parameters as the type.
+		type Wrapper<T: Clone>: Clone {
+			fn new(_: T) -> Self;
+			fn map(self, _: fn(T) -> T) -> Self;
+			fn get(&self) -> &T;
+			// A generic method can use the type's parameters alongside its own.
+			fn convert<U>(self, _: fn(T) -> U) -> Wrapper<U>;
+			// A method that needs more than the type's bounds has to say so, the same way it would in its own `impl` block.
+			fn take(&mut self) -> T where T: Default;
+			field value: T;
+		}
+
+		// The type is always named with the declared parameters, so the real one can be looser than declared, but not stricter.
+		type Holder<T: Clone + 'static>;
+
+		type Pair<A, B> where A: Copy {
+			fn new(_: A, _: B) -> Self;
+			fn swap(self) -> Pair<B, A>;
+		}
+
+		// Every generic method gets its own function, so methods with the same name don't clash.
+		type Left {
+			fn new<T: Into<u32>>(_: T) -> Self;
+			field value: u32;
+		}
+
+		type Right {
+			fn new<T: Into<u32>>(_: T) -> Self;
+			field value: u32;
+		}
+
+		// A const parameter is passed along the same way as a type.
+		fn zeros<const N: usize>() -> [u8; N];
+
+		type Array<const N: usize> {
+			fn new() -> Self;
+			fn first<const M: usize>(&self) -> [u8; M];
+			field bytes: [u8; N];
+		}
+
+		type Bytes<const N: usize> = [u8; N];
+
+		// A default is checked by naming the type without it, which has to be the same as naming it with the declared one.
+		type Buffer<T = u8> {
+			fn len(&self) -> usize;
+		}
+		type Stack<T = u8> = Vec<T>;
+
+		// Lifetimes on their own are bound by the pointer, `for<'a> fn(&'a u8) -> &'a u8`, so they're checked like any other method.
+		// One with a bound, like `'a: 'b`, goes through a function that declares it instead, just like a type parameter.
+		fn zero() -> &'static u8;
+		fn same<'a>(_: &'a u8) -> &'a u8;
+		// A higher-ranked parameter is kept as it's written, so one that only takes a single lifetime, like `fn(&'static str) -> &'static str`, won't pass.
+		fn apply(_: for<'a> fn(&'a str) -> &'a str) -> usize;
+		fn visit(_: impl for<'a> Fn(&'a u8));
+
+		// A function that's more general would still pass, unless it has to tie its lifetimes together the same way.
+		#[strict_lifetimes]
+		fn longest<'a>(_: &'a str, _: &'a str) -> &'a str;
+	}
+}
+
+fn main() {
+}
@@ -0,0 +1,124 @@
+#[derive(Clone)]
+pub struct Wrapper<T> {
+	pub value: T,
+}
+
+impl<T> Wrapper<T> {
+	pub fn new(value: T) -> Self {
+		Wrapper {
+			value,
+		}
+	}
+
+	pub fn map(self, func: fn(T) -> T) -> Self {
+		Wrapper::new(func(self.value))
+	}
+
+	pub fn get(&self) -> &T {
+		&self.value
+	}
+
+	pub fn convert<U>(self, func: fn(T) -> U) -> Wrapper<U> {
+		Wrapper::new(func(self.value))
+	}
+}
+
+impl<T: Default> Wrapper<T> {
+	pub fn take(&mut self) -> T {
+		std::mem::take(&mut self.value)
+	}
+}
+
+pub struct Pair<A, B>(A, B);
+
+impl<A, B> Pair<A, B> {
+	pub fn new(a: A, b: B) -> Self {
+		Pair(a, b)
+	}
+
+	pub fn swap(self) -> Pair<B, A> {
+		Pair(self.1, self.0)
+	}
+}
+
+pub struct Left {
+	pub value: u32,
+}
+
+impl Left {
+	pub fn new<T: Into<u32>>(value: T) -> Self {
+		Left {
+			value: value.into(),
+		}
+	}
+}
+
+pub struct Right {
+	pub value: u32,
+}
+
+impl Right {
+	pub fn new<T: Into<u32>>(value: T) -> Self {
+		Right {
+			value: value.into(),
+		}
+	}
+}
+
+pub fn zeros<const N: usize>() -> [u8; N] {
+	[0; N]
+}
+
+pub struct Array<const N: usize> {
+	pub bytes: [u8; N],
+}
+
+impl<const N: usize> Array<N> {
+	pub fn new() -> Self {
+		Array {
+			bytes: [0; N],
+		}
+	}
+
+	pub fn first<const M: usize>(&self) -> [u8; M] {
+		let mut bytes = [0; M];
+		bytes.copy_from_slice(&self.bytes[..M]);
+		bytes
+	}
+}
+
+pub type Bytes<const N: usize> = [u8; N];
+
+pub struct Buffer<T = u8> {
+	pub data: Vec<T>,
+}
+
+impl<T> Buffer<T> {
+	pub fn len(&self) -> usize {
+		self.data.len()
+	}
+}
+
+pub type Stack<T = u8> = Vec<T>;
+
+pub fn zero() -> &'static u8 {
+	&0
+}
+
+pub fn same(value: &u8) -> &u8 {
+	value
+}
+
+pub fn apply(func: for<'a> fn(&'a str) -> &'a str) -> usize {
+	func("value").len()
+}
+
+pub fn visit(func: impl for<'a> Fn(&'a u8)) {
+	func(&0)
+}
+
+pub fn longest<'a>(a: &'a str, b: &'a str) -> &'a str {
+	if a.len() >= b.len() { a } else { b }
+}
+
+pub struct Holder<T>(std::marker::PhantomData<T>);
@@ -0,0 +1,104 @@
+use std::os::raw::{c_char, c_int};
+use std::sync::atomic::AtomicUsize;
+
+mod inner {
+	pub struct ReexportedStruct;
+
+	pub fn reexported_method() {
+	}
+}
+
+pub use self::inner::{reexported_method, ReexportedStruct};
+
+pub fn method(_: u32) -> u8 {
+	0
+}
+
+pub fn other_method(_: u32) -> u8 {
+	0
+}
+
+pub fn visible_method(_: u32) -> u8 {
+	0
+}
+
+pub fn named_method(value: u32) -> u8 {
+	value as u8
+}
+
+pub fn impl_method(value: impl Into<u32>) -> impl Iterator<Item = u8> {
+	value.into().to_le_bytes().to_vec().into_iter()
+}
+
+pub const fn const_method(_: u32) -> u8 {
+	0
+}
+
+pub async fn async_method(_: u32) -> u8 {
+	0
+}
+
+/// # Safety
+/// The pointer has to be valid for a read.
+pub unsafe fn unsafe_method(value: *const u8) -> u8 {
+	*value
+}
+
+extern "C" {
+	#[link_name = "printf"]
+	pub fn variadic_method(format: *const c_char, ...) -> c_int;
+}
+
+pub struct MyStruct;
+
+#[derive(Clone, Debug)]
+pub struct MyClonedStruct;
+
+pub struct MyOtherStruct {
+	pub value: u32,
+}
+
+impl MyOtherStruct {
+	pub const MAX: usize = 8;
+
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+impl Iterator for MyOtherStruct {
+	type Item = u32;
+
+	fn next(&mut self) -> Option<u32> {
+		None
+	}
+}
+
+pub struct MyWrapper<T>(T);
+
+impl<T: Clone> MyWrapper<T> {
+	pub fn get(&self) -> &T {
+		&self.0
+	}
+}
+
+pub const PAGE_SIZE: usize = 4096;
+
+pub static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub trait MyTrait {
+	fn method(&self, _: u32) -> u8;
+}
+
+#[allow(dead_code)]
+pub enum MyEnum {
+	Tuple(u8),
+	Struct { x: u32, y: u32 },
+	Unit,
+}
+
+pub mod my_submod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
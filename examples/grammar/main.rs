@@ -0,0 +1,71 @@
+//! Every item from the crate documentation, which makes sure the documented grammar keeps parsing.
+
+extern crate def_mod;
+
+use def_mod::def_mod;
+use std::fmt::Debug;
+use std::os::raw::{c_char, c_int};
+use std::sync::atomic::AtomicUsize;
+
+def_mod! {
+	mod grammar {
+		fn method(_: u32) -> u8;
+
+		#[error = "other_method must be fn(u32) -> u8"]
+		fn other_method(_: u32) -> u8;
+
+		pub(crate) fn visible_method(_: u32) -> u8;
+
+		#[check_names]
+		fn named_method(value: u32) -> u8;
+
+		fn impl_method(_: impl Into<u32>) -> impl Iterator<Item = u8>;
+
+		const fn const_method(_: u32) -> u8;
+
+		async fn async_method(_: u32) -> u8;
+
+		unsafe fn unsafe_method(_: *const u8) -> u8;
+
+		unsafe extern "C" fn variadic_method(_: *const c_char, ...) -> c_int;
+
+		/// Doc comments can be written anywhere, but they're left out of the generated checks.
+		type MyStruct;
+
+		type MyClonedStruct: Clone + Send + Debug;
+
+		type MyOtherStruct {
+			fn method(_: u32) -> u8;
+			field value: u32;
+			const MAX: usize;
+			type Iterator::Item;
+		}
+
+		type MyWrapper<T: Clone> {
+			fn get(&self) -> &T;
+		}
+
+		use fn reexported_method;
+		use ReexportedStruct;
+
+		const PAGE_SIZE: usize;
+		static COUNTER: AtomicUsize;
+
+		trait MyTrait {
+			fn method(&self, _: u32) -> u8;
+		}
+
+		enum MyEnum {
+			Tuple(u8),
+			Struct { x: u32, y: u32 },
+			Unit,
+		}
+
+		mod my_submod {
+			fn method(_: u32) -> u8;
+		}
+	}
+}
+
+fn main() {
+}
@@ -0,0 +1,120 @@
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+	pub timeout: Duration,
+	pub retries: u32,
+	pub fallback: Option<Box<Config>>,
+	secret: u8,
+}
+
+impl Config {
+	pub fn new() -> Self {
+		Config {
+			timeout: Duration::from_secs(1),
+			retries: 3,
+			fallback: None,
+			secret: 0,
+		}
+	}
+
+	pub fn with_timeout(timeout: Duration) -> Self {
+		Config {
+			timeout,
+			..Config::new()
+		}
+	}
+
+	pub fn secret(&self) -> u8 {
+		self.secret
+	}
+
+	pub fn check(&self, _: SelfCheck) -> Option<Self> {
+		Some(self.clone())
+	}
+
+	pub fn into_fallback(self: Box<Self>) -> Self {
+		Config {
+			fallback: Some(self),
+			..Config::new()
+		}
+	}
+
+	pub fn shared(self: Rc<Self>) -> u32 {
+		self.retries
+	}
+
+	pub fn pinned(self: Pin<&mut Self>) -> u32 {
+		self.retries
+	}
+
+	pub fn with_retries(&self, retries: u32) -> Self {
+		Config {
+			retries,
+			..self.clone()
+		}
+	}
+}
+
+pub fn merge(base: Config, other: &Config) -> Config {
+	Config {
+		retries: base.retries.max(other.retries),
+		..base
+	}
+}
+
+pub const MERGE: fn(Config, &Config) -> Config = merge;
+
+pub struct SelfCheck;
+
+pub type Retries = u32;
+
+pub type Fallback<T> = Option<Box<T>>;
+
+pub struct Limits;
+
+impl Limits {
+	pub const MAX: usize = 8;
+	pub const NAME: &str = "limits";
+	pub const CLAMP: fn(&Self, usize) -> usize = Self::clamp;
+
+	pub const fn max() -> usize {
+		Self::MAX
+	}
+
+	pub const fn clamp(&self, value: usize) -> usize {
+		if value > Self::MAX {
+			Self::MAX
+		} else {
+			value
+		}
+	}
+
+	pub fn tag(&self) -> [u8; 4] {
+		*b"self"
+	}
+}
+
+pub struct Parser;
+
+impl Iterator for Parser {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		None
+	}
+}
+
+impl std::str::FromStr for Parser {
+	type Err = ();
+
+	fn from_str(_: &str) -> Result<Self, ()> {
+		Ok(Parser)
+	}
+}
+
+pub struct Meters(pub f64);
+
+pub struct Range<T>(pub T, pub T);
@@ -0,0 +1,10 @@
+#[allow(dead_code)]
+pub enum Event {
+	Key(u8),
+	Resize {
+		w: u32,
+		h: u32,
+	},
+	Focus(bool),
+	Quit,
+}
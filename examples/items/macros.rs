@@ -0,0 +1,8 @@
+macro_rules! retry {
+	($times:expr, $body:expr) => {
+		(0..$times).find_map(|_| $body)
+	};
+}
+
+// A `macro_rules!` can only be named by a path once it's re-exported.
+pub(crate) use retry;
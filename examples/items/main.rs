@@ -0,0 +1,120 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+def_mod! {
+	// Any signature that names a type which needs std, like `String` or `Vec`, is warned about.
+	#[no_std]
+	mod events {
+		// The enum can have more variants than the ones declared here.
+		enum Event {
+			/// Doc comments are fine anywhere, they're just left out of the checks.
+			Key(u8),
+			Resize { w: u32, h: u32 },
+			Quit,
+		}
+	}
+
+	mod config {
+		// The type has to implement each of the traits.
+		/// The configuration that's loaded on startup.
+		type Config: Clone + Debug + PartialEq<Self> {
+			/// How long to wait before giving up.
+			field timeout: Duration;
+			// The `field` keyword is optional.
+			retries: u32;
+			fallback: Option<Box<Self>>;
+			/// Creates the default configuration.
+			// Along with the check, this generates a test that calls it, which is run by `cargo test`.
+			#[smoke_test]
+			fn new() -> Self;
+			fn secret(&self) -> u8;
+			// Only `Self` itself is replaced, any other name that contains it is left as is.
+			// A bound on a type that's already known, like `Self: Sized`, is left out, as the method wouldn't exist if it didn't hold.
+			fn check(&self, _: SelfCheck) -> Option<Self> where Self: Sized;
+			// A `_` leaves the type to the method, so only the number of parameters is checked.
+			fn with_retries(&self, _) -> _;
+			// A receiver can have a type, as long as it's one that `Self` can be called through.
+			fn into_fallback(self: Box<Self>) -> Self;
+			fn shared(self: Rc<Self>) -> u32;
+			fn pinned(self: Pin<&mut Self>) -> u32;
+		}
+
+		// This is handy for a module that's still changing, where only the shape of the function is settled.
+		fn merge(_, _) -> _;
+
+		// A single function can be checked without declaring the type around it, though it has to name the type instead of using `Self`.
+		#[smoke_test]
+		fn Config::with_timeout(_: Duration) -> Config;
+
+		// A function pointer that's stored in a const is checked the same way as any other const, so its type has to match.
+		const MERGE: fn(Config, &Config) -> Config;
+
+		// An alias has to be the same type as the target, generics included.
+		type Retries = u32;
+		type Fallback<T> = Option<Box<T>>;
+
+		// A unit or tuple struct can be checked for its constructor, which fails if it's changed to have named fields.
+		type SelfCheck {
+			unit;
+		}
+		type Meters {
+			tuple(f64);
+		}
+		type Range<T> {
+			tuple(T, T);
+		}
+
+		type Limits {
+			/// The most that's allowed.
+			const MAX: usize;
+			const NAME: &str;
+			const CLAMP: fn(&Self, usize) -> usize;
+			// This also checks that it can be called in a const context.
+			#[smoke_test]
+			const fn max() -> usize;
+			const fn clamp(&self, _: usize) -> usize;
+			// A macro's input is left alone, so this is `"Self".len()`, and not the length of the type's name.
+			fn tag(&self) -> [u8; stringify!(Self).len()];
+		}
+
+		type Parser {
+			// The trait has to be named, unless it's an inherent associated type.
+			type Iterator::Item;
+			type std::str::FromStr::Err;
+		}
+	}
+
+	mod macros {
+		// Only the name of the macro is checked, as it can't be named without calling it.
+		pub(crate) macro retry;
+	}
+
+	// The pointers are bound with `let` instead of being assigned to consts.
+	#[assert_style = "let"]
+	// A `cfg_attr` goes on the declaration, the same as any other attribute.
+	#[cfg_attr(unix, doc = "Exiting, and never returning.")]
+	mod process {
+		// A diverging function is checked the same way, so one that returns can't stand in for it.
+		fn exit_with(_: i32) -> !;
+		fn fail(_: &str) -> !;
+		// The attributes that a `cfg_attr` applies are left out of the check the same way as they would be on their own.
+		#[cfg_attr(unix, inline)]
+		#[cfg_attr(debug_assertions, allow(unused))]
+		fn run_forever(_: fn()) -> !;
+		async fn halt() -> !;
+		// Only attributes like `cfg` and `allow` are carried over to the checks, so this doesn't warn, and neither does checking a deprecated function.
+		#[deprecated = "use `exit_with` instead"]
+		#[inline]
+		fn exit(_: i32) -> !;
+	}
+}
+
+fn main() {
+	assert_eq!(macros::retry!(3, Some(1)), Some(1));
+	assert_eq!(config::Meters(1.5).0, 1.5);
+}
@@ -0,0 +1,24 @@
+pub fn exit_with(code: i32) -> ! {
+	std::process::exit(code)
+}
+
+pub fn fail(message: &str) -> ! {
+	panic!("{}", message)
+}
+
+pub fn run_forever(tick: fn()) -> ! {
+	loop {
+		tick();
+	}
+}
+
+pub async fn halt() -> ! {
+	loop {
+		std::thread::park();
+	}
+}
+
+#[deprecated = "use `exit_with` instead"]
+pub fn exit(code: i32) -> ! {
+	exit_with(code)
+}
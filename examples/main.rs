@@ -1,6 +1,7 @@
 extern crate def_mod;
 
 use def_mod::def_mod;
+use std::fmt::Display;
 
 def_mod! {
 	#[cfg(windows)] = "sys/win.rs"
@@ -10,15 +11,135 @@ def_mod! {
 		type Test {
 			// Try changing the method's name/signature.
         	fn new() -> Test;
+			// These only exist in the unix module, so they're only checked there.
+			#[cfg(unix)]
+			fn extra(&self) -> Self;
+			#[cfg(unix)]
+			fn convert<T: From<Self>>(self) -> T;
+			#[cfg(unix)]
+			const fn id(&self) -> u32;
         }
+
+		// The same item can be declared more than once, as long as only one of them is active.
+		#[cfg(target_pointer_width = "64")]
+		fn size() -> u64;
+		#[cfg(not(target_pointer_width = "64"))]
+		fn size() -> u32;
+
+		#[cfg(target_pointer_width = "64")]
+		type Word = u64;
+		#[cfg(not(target_pointer_width = "64"))]
+		type Word;
+	}
+
+	// Any other platform reuses the unix module.
+	#[cfg(unix)] = "platform/nix.rs"
+	#[cfg(windows)] = "platform/win.rs"
+	#[cfg(not(any(unix, windows)))] = "~unix"
+	mod platform {
+		fn method(_: u32) -> u8;
+	}
+
+	// The first of the paths that exists is used, so a vendored copy can take precedence when it's there.
+	#[cfg(unix)] = ["vendor/fallback.rs", "fallback/nix.rs"]
+	#[cfg(not(unix))] = "~unix"
+	mod fallback {
+		fn method(_: u32) -> u8;
+	}
+
+	// None of the paths have to be active, in which case the module isn't declared, and neither is anything that refers to it.
+	#[cfg(all(unix, windows))] = "platform/nix.rs"
+	#[cfg(all(target_os = "linux", target_os = "macos"))] = "platform/win.rs"
+	#[as_trait(UnreachableApi)]
+	mod unreachable {
+		fn method(_: u32) -> u8;
+	}
+
+	// The platform shorthands expand to a `target_os` cfg, so `~linux` refers to the first one.
+	#[linux = "shorthand/nix.rs"]
+	#[macos = "~linux"]
+	#[windows = "shorthand/win.rs"]
+	mod shorthand {
+		fn method(_: u32) -> u8;
+	}
+
+	// The same module can be declared again, as long as the declarations can't both be active.
+	#[cfg(unix)] = "native/nix.rs"
+	mod native {
+		fn method(_: u32) -> u8;
+	}
+	#[cfg(not(unix))] = "native/win.rs"
+	mod native {
+		#[error = "`native::method` must be `fn(u32) -> u8`"]
+		fn method(_: u32) -> u8;
+	}
+
+	// This is only checked on windows, so the module doesn't even exist anywhere else.
+	#[cfg(windows)]
+	mod windows_only {
+		fn method(_: u32) -> u8;
+	}
+
+	// Each module in the block is gated by the cfg, on top of their own attributes.
+	cfg(unix) {
+		#[cfg(target_os = "linux")] = "grouped/nix.rs"
+		#[cfg(not(target_os = "linux"))] = "~linux"
+		mod grouped {
+			fn method(_: u32) -> u8;
+		}
+
+		// Blocks can be nested as well, and `windows_method` only exists in the windows module.
+		cfg(not(target_os = "linux")) {
+			#[path = "grouped/other.rs"]
+			mod grouped_other;
+		}
+	}
+	cfg(windows) {
+		#[path = "sys/win.rs"]
+		mod grouped_windows {
+			fn windows_method() -> u8;
+		}
+	}
+
+	// An attribute can be targeted at only the declaration, or only the load function.
+	#[mod: doc(hidden)]
+	#[mod: allow(dead_code)]
+	#[load: inline(never)]
+	mod targeted {
+		fn method(_: u32) -> u8;
 	}
 
+	// These were removed from the module, and this makes sure they don't come back.
+	#[sealed(legacy_method, LegacyStruct)]
 	mod other {
+		// This message is used as the error if the signature of the method doesn't match.
+		#[error = "`other::method` must be `fn(u64, u8) -> u32`"]
 		fn method(_: u64, _: u8) -> u32;
+		// A visibility asserts that the item is at least as visible.
+		pub const LIMIT: usize;
+		// Every parameter has to be named, and the method is called through them.
+		#[check_names]
+		fn connect(host: &str, port: u16) -> bool;
+		// A pointer can't hold an `impl Trait`, so these are called through a function with the same signature instead.
+		fn bytes(_: &str) -> impl Iterator<Item = u8> + '_;
+		fn describe(_: impl Display, _: Vec<impl Into<u64>>) -> Option<impl Display>;
+		// The future that it returns is checked for its output.
+		async fn fetch(_: &str) -> Vec<u8>;
+		// It has to actually be unsafe, a safe function won't do.
+		unsafe fn danger(_: *const u8) -> u8;
+		// Trait objects are kept as they are, along with any of their bounds.
+		fn handler(_: Box<dyn Fn(u8) -> u8>) -> Box<dyn Fn(u8) -> u8 + Send>;
+		fn show(_: &dyn Display) -> &dyn Display;
+		static NAME: &'static str;
 
-		type MyStruct {
+		pub(crate) type MyStruct {
 			fn new() -> Self;
+			#[check_names]
+			async fn load(&self, id: u32) -> Self;
 			fn generic<T>(self, _: u32, other: T, func: fn(T) -> Self) -> Self;
+			// `Self` is replaced inside of a trait object too.
+			fn visit(&self, _: &dyn Fn(&Self) -> u8) -> Box<dyn Iterator<Item = Self> + Send>;
+			fn then(self, _: Box<dyn FnOnce(Self) -> Self + Send + 'static>) -> Self;
 		}
 		fn generic<'a, T: 'a>(_: MyStruct, value: u32, other: &'a T, func: fn(T) -> MyStruct) -> MyStruct;
 	}
@@ -0,0 +1,3 @@
+pub struct Addr {
+	pub port: u16,
+}
@@ -0,0 +1,43 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	mod addr {
+		type Addr;
+	}
+
+	mod net {
+		// A path that starts with `super` is relative to the module, the same as it would be in its source.
+		fn resolve(_: &str) -> super::addr::Addr;
+
+		// The submodules are declared by `net` itself, so these only check what they export.
+		// Anything that was taken out of the module can be sealed, so it doesn't come back.
+		#[sealed(listen)]
+		pub mod tcp {
+			fn connect(_: &str, _: u16) -> bool;
+			fn connect_to(_: &super::super::addr::Addr) -> bool;
+			const TIMEOUT: u64;
+
+			// The submodule imports everything it exports, just like the top level, so a bound can name one of its own types.
+			type Stream;
+			fn open<T>(_: T) -> Stream
+			where
+				T: Into<Stream>,
+				Stream: From<T>;
+		}
+
+		// A path can be given, but only the attribute is used to gate the checks.
+		#[cfg(unix)] = "net/unix.rs"
+		#[cfg(not(unix))] = "~unix"
+		mod sys {
+			fn socket() -> i32;
+		}
+
+		// Without a body, it only checks that the module exists.
+		mod udp;
+	}
+}
+
+fn main() {
+}
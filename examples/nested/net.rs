@@ -0,0 +1,38 @@
+pub fn resolve(_: &str) -> super::addr::Addr {
+	super::addr::Addr {
+		port: 80,
+	}
+}
+
+pub mod tcp {
+	pub const TIMEOUT: u64 = 30;
+
+	pub fn connect(_: &str, _: u16) -> bool {
+		false
+	}
+
+	pub fn connect_to(addr: &super::super::addr::Addr) -> bool {
+		connect("localhost", addr.port)
+	}
+
+	pub struct Stream;
+
+	impl From<u16> for Stream {
+		fn from(_: u16) -> Self {
+			Stream
+		}
+	}
+
+	pub fn open<T>(port: T) -> Stream where T: Into<Stream>, Stream: From<T> {
+		port.into()
+	}
+}
+
+pub mod sys {
+	pub fn socket() -> i32 {
+		-1
+	}
+}
+
+pub mod udp {
+}
@@ -0,0 +1,30 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+use std::num::ParseIntError;
+
+def_mod! {
+	// The module's `Result` would shadow the prelude's in every signature, so nothing is imported from it.
+	// Anything that's declared is named by its path instead, which is why `Self` still works.
+	#[no_glob]
+	#[as_trait(Results)]
+	mod results {
+		type Result {
+			field code: u32;
+			fn new(_: u32) -> Self;
+			fn parse(_: &str) -> Result<Self, ParseIntError>;
+		}
+
+		fn parse_code(_: &str) -> Result<u32, ParseIntError>;
+		// Anything else from the module has to be named by its path.
+		fn is_ok(_: &crate::results::Result) -> bool;
+
+		// A nested module leaves it out too, but it's still checked that it exists.
+		mod codes;
+	}
+}
+
+fn main() {
+	let result = results::Result::parse("0").unwrap();
+	assert!(results::is_ok(&result));
+}
@@ -0,0 +1,30 @@
+use std::num::ParseIntError;
+
+// This shadows the prelude's `Result`, so anything that imports everything from here gets this one instead.
+pub struct Result {
+	pub code: u32,
+}
+
+impl Result {
+	pub fn new(code: u32) -> Self {
+		Result {
+			code,
+		}
+	}
+
+	pub fn parse(value: &str) -> std::result::Result<Self, ParseIntError> {
+		value.parse().map(Result::new)
+	}
+}
+
+pub fn parse_code(value: &str) -> std::result::Result<u32, ParseIntError> {
+	value.parse()
+}
+
+pub fn is_ok(result: &Result) -> bool {
+	result.code == codes::OK
+}
+
+pub mod codes {
+	pub const OK: u32 = 0;
+}
@@ -0,0 +1,34 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	mod vector {
+		type Vec3 {
+			fn new(_: f32, _: f32, _: f32) -> Self;
+
+			// Each of these is checked against the trait's method, so an inherent `add` wouldn't pass.
+			op Add(self, Self) -> Self;
+			op AddAssign(&mut self, Self);
+			// The trait's parameter is taken from the second parameter, as it'd otherwise default to `Self`.
+			op Mul(self, f32) -> Self;
+			op Neg(self) -> Self;
+			op Index(&self, usize) -> &f32;
+		}
+
+		type Grid<T> {
+			op Index(&self, usize) -> &T;
+		}
+	}
+}
+
+fn main() {
+	let mut value = vector::Vec3::new(1.0, 2.0, 3.0);
+	value += -value * 2.0;
+	assert_eq!(value[1], -2.0);
+
+	let grid = vector::Grid {
+		cells: vec![1, 2, 3],
+	};
+	assert_eq!(grid[2], 3);
+}
@@ -0,0 +1,72 @@
+use std::ops::{Add, AddAssign, Index, Mul, Neg};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3 {
+	pub x: f32,
+	pub y: f32,
+	pub z: f32,
+}
+
+impl Vec3 {
+	pub fn new(x: f32, y: f32, z: f32) -> Self {
+		Vec3 {
+			x,
+			y,
+			z,
+		}
+	}
+}
+
+impl Add for Vec3 {
+	type Output = Vec3;
+
+	fn add(self, other: Vec3) -> Vec3 {
+		Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+	}
+}
+
+impl AddAssign for Vec3 {
+	fn add_assign(&mut self, other: Vec3) {
+		*self = *self + other;
+	}
+}
+
+impl Mul<f32> for Vec3 {
+	type Output = Vec3;
+
+	fn mul(self, scale: f32) -> Vec3 {
+		Vec3::new(self.x * scale, self.y * scale, self.z * scale)
+	}
+}
+
+impl Neg for Vec3 {
+	type Output = Vec3;
+
+	fn neg(self) -> Vec3 {
+		self * -1.0
+	}
+}
+
+impl Index<usize> for Vec3 {
+	type Output = f32;
+
+	fn index(&self, index: usize) -> &f32 {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			_ => &self.z,
+		}
+	}
+}
+
+pub struct Grid<T> {
+	pub cells: Vec<T>,
+}
+
+impl<T> Index<usize> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, index: usize) -> &T {
+		&self.cells[index]
+	}
+}
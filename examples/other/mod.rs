@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+pub fn method(_: u64, _: u8) -> u32 {
+	0
+}
+
+pub fn connect(host: &str, port: u16) -> bool {
+	!host.is_empty() && port != 0
+}
+
+pub fn bytes(value: &str) -> impl Iterator<Item = u8> + '_ {
+	value.bytes()
+}
+
+pub fn describe(value: impl Display, counts: Vec<impl Into<u64>>) -> Option<impl Display> {
+	let total: u64 = counts.into_iter().map(Into::into).sum();
+	Some(format!("{}: {}", value, total))
+}
+
+pub async fn fetch(_: &str) -> Vec<u8> {
+	vec![]
+}
+
+/// # Safety
+/// The pointer has to be valid for a read.
+pub unsafe fn danger(value: *const u8) -> u8 {
+	*value
+}
+
+pub fn handler(handler: Box<dyn Fn(u8) -> u8>) -> Box<dyn Fn(u8) -> u8 + Send> {
+	let value = handler(1);
+	Box::new(move |other| value + other)
+}
+
+pub fn show(value: &dyn Display) -> &dyn Display {
+	value
+}
+
+pub const LIMIT: usize = 16;
+
+pub static NAME: &str = "other";
+
+pub struct MyStruct;
+
+impl MyStruct {
+	pub fn new() -> Self {
+		MyStruct
+	}
+	pub async fn load(&self, _: u32) -> Self {
+		MyStruct
+	}
+	pub fn generic<T>(self, _: u32, _: T, _: fn(T) -> Self) -> Self {
+		MyStruct
+	}
+	pub fn visit(&self, visitor: &dyn Fn(&Self) -> u8) -> Box<dyn Iterator<Item = Self> + Send> {
+		visitor(self);
+		Box::new(std::iter::empty())
+	}
+	pub fn then(self, func: Box<dyn FnOnce(Self) -> Self + Send + 'static>) -> Self {
+		func(self)
+	}
+}
+pub fn generic<'a, T: 'a>(_: MyStruct, _: u32, _: &'a T, _: fn(T) -> MyStruct) -> MyStruct {
+	MyStruct
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub fn method(_: u32) -> u8 {
+	2
+}
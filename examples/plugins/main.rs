@@ -0,0 +1,27 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+use plugin::Plugin;
+
+def_mod! {
+	// The methods are the trait's, and each of them is checked to be callable on a `dyn Plugin`.
+	// That fails unless the trait is object safe, so it's sure to be usable as a plugin.
+	#[object_safe(Plugin)]
+	mod plugin {
+		fn name(&self) -> &'static str;
+		fn run(&mut self, _: u32) -> bool;
+		fn describe(&self, _: &str) -> String;
+		fn unload(self: Box<Self>) -> u32;
+
+		// Anything else is checked as usual.
+		type Echo: Plugin;
+	}
+}
+
+fn main() {
+	let mut plugin: Box<dyn Plugin> = Box::new(plugin::Echo { runs: 0 });
+	assert!(plugin.run(1));
+	assert_eq!(plugin.describe("plugin: "), "plugin: echo");
+	assert_eq!(plugin.unload(), 1);
+	plugin::Echo { runs: 0 }.finish();
+}
@@ -0,0 +1,32 @@
+pub trait Plugin {
+	fn name(&self) -> &'static str;
+
+	fn run(&mut self, input: u32) -> bool;
+
+	fn describe(&self, prefix: &str) -> String {
+		format!("{}{}", prefix, self.name())
+	}
+
+	fn unload(self: Box<Self>) -> u32;
+
+	fn finish(self) where Self: Sized {}
+}
+
+pub struct Echo {
+	pub runs: u32,
+}
+
+impl Plugin for Echo {
+	fn name(&self) -> &'static str {
+		"echo"
+	}
+
+	fn run(&mut self, input: u32) -> bool {
+		self.runs += 1;
+		input > 0
+	}
+
+	fn unload(self: Box<Self>) -> u32 {
+		self.runs
+	}
+}
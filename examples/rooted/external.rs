@@ -0,0 +1,11 @@
+pub fn thing(value: u32) -> u32 {
+	value + 1
+}
+
+pub struct Thing;
+
+impl Thing {
+	pub fn new() -> Self {
+		Thing
+	}
+}
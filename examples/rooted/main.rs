@@ -0,0 +1,63 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+// The module is declared as usual, somewhere other than where it's checked.
+mod external;
+
+// The same module, under a different path.
+mod mirror {
+	pub(crate) use super::external;
+}
+
+mod checks {
+	use def_mod::def_mod;
+
+	def_mod! {
+		// The checks are qualified with the root instead of `self`, and the module isn't declared again.
+		#[root = "crate"]
+		#[as_trait(ExternalApi)]
+		#[sealed(legacy_thing)]
+		mod external {
+			pub fn thing(_: u32) -> u32;
+
+			type Thing {
+				fn new() -> Self;
+			}
+		}
+	}
+
+	def_mod! {
+		// This would also be `_load_external`, which is already taken by the invocation above.
+		#[root = "crate::mirror"]
+		#[load_fn = "_load_mirrored_external"]
+		mod external {
+			fn thing(_: u32) -> u32;
+		}
+	}
+
+	def_mod! {
+		// Instead of naming the load function, everything that's generated can be given a prefix of its own, which makes it `__dm_load_external`.
+		#[root = "crate"]
+		#[prefix = "__dm_"]
+		mod external {
+			fn thing(_: u32) -> u32;
+
+			type Thing {
+				fn new() -> Self;
+			}
+		}
+	}
+}
+
+def_mod! {
+	// An extern crate can also be the root, as long as it's written with the leading `::`.
+	#[root = "::std"]
+	mod cmp {
+		fn max(_: u32, _: u32) -> u32;
+	}
+}
+
+fn main() {
+	assert_eq!(external::thing(1), 2);
+}
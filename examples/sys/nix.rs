@@ -8,4 +8,32 @@ impl Test {
 	pub fn new() -> Test {
 		Test
 	}
-}
\ No newline at end of file
+
+	pub fn extra(&self) -> Self {
+		Test
+	}
+
+	pub fn convert<T: From<Self>>(self) -> T {
+		T::from(self)
+	}
+
+	pub const fn id(&self) -> u32 {
+		0
+	}
+}
+
+#[cfg(target_pointer_width = "64")]
+pub fn size() -> u64 {
+	8
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+pub fn size() -> u32 {
+	4
+}
+
+#[cfg(target_pointer_width = "64")]
+pub type Word = u64;
+
+#[cfg(not(target_pointer_width = "64"))]
+pub struct Word(pub u32);
@@ -2,10 +2,30 @@ pub fn method(_: u32) -> u8 {
 	0
 }
 
+pub fn windows_method() -> u8 {
+	0
+}
+
 pub struct Test;
 
 impl Test {
 	pub fn new() -> Test {
 		Test
 	}
-}
\ No newline at end of file
+}
+
+#[cfg(target_pointer_width = "64")]
+pub fn size() -> u64 {
+	8
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+pub fn size() -> u32 {
+	4
+}
+
+#[cfg(target_pointer_width = "64")]
+pub type Word = u64;
+
+#[cfg(not(target_pointer_width = "64"))]
+pub struct Word(pub u32);
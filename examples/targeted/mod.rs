@@ -0,0 +1,6 @@
+pub fn method(_: u32) -> u8 {
+	0
+}
+
+// Nothing uses this, which the attribute on the declaration allows.
+fn unused() {}
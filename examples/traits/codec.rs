@@ -0,0 +1,101 @@
+use std::fmt::Debug;
+
+pub trait Encoder: Clone + Debug {
+	type Out;
+	type Error: Debug + Send;
+
+	fn encode(&self, bytes: &[u8]) -> usize;
+
+	fn finish(self) -> Self::Out;
+
+	fn verify(&self, bytes: &[u8]) -> Result<usize, Self::Error>;
+
+	fn name() -> &'static str;
+
+	fn chain(self, _: Self) -> Self {
+		self
+	}
+
+	fn boxed_name(self: Box<Self>) -> &'static str {
+		Self::name()
+	}
+
+	fn encode_all<'a, T: AsRef<[u8]> + 'a>(&self, values: &'a [T]) -> usize {
+		values.iter()
+			.map(|value| self.encode(value.as_ref()))
+			.sum()
+	}
+
+	fn same_as<T>(&self, other: &T) -> bool where T: PartialEq<Self> {
+		other == self
+	}
+}
+
+pub trait Decoder {
+}
+
+pub fn encode_with<T: Encoder>(encoder: &T, bytes: &[u8]) -> usize {
+	encoder.encode(bytes)
+}
+
+pub fn encode_owned<T: Clone + Encoder + 'static>(encoder: T) -> usize {
+	encoder.clone().encode(&[])
+}
+
+pub fn decode_with<T: Decoder, U: AsRef<[u8]>>(_: &T, bytes: U) -> Vec<u8> {
+	bytes.as_ref().to_vec()
+}
+
+pub fn process<T>(value: T) -> Utf8 where T: Into<Utf8>, Utf8: From<T> {
+	value.into()
+}
+
+#[derive(Clone, Debug)]
+pub struct Utf8;
+
+impl Utf8 {
+	pub fn new() -> Self {
+		Utf8
+	}
+}
+
+impl Encoder for Utf8 {
+	type Out = String;
+	type Error = std::str::Utf8Error;
+
+	fn encode(&self, bytes: &[u8]) -> usize {
+		bytes.len()
+	}
+
+	fn finish(self) -> String {
+		String::new()
+	}
+
+	fn verify(&self, bytes: &[u8]) -> Result<usize, Self::Error> {
+		std::str::from_utf8(bytes).map(str::len)
+	}
+
+	fn name() -> &'static str {
+		"utf8"
+	}
+}
+
+impl std::fmt::Display for Utf8 {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		f.write_str(Self::name())
+	}
+}
+
+impl From<u32> for Utf8 {
+	fn from(_: u32) -> Self {
+		Utf8
+	}
+}
+
+pub struct Frame<T>(pub T);
+
+impl<T> From<T> for Frame<T> {
+	fn from(value: T) -> Self {
+		Frame(value)
+	}
+}
@@ -0,0 +1,60 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+def_mod! {
+	mod codec {
+		// The trait's supertraits don't need to be declared.
+		trait Encoder {
+			// The associated type has to have at least these bounds, and `Self::Out` refers to it.
+			type Out;
+			type Error: std::fmt::Debug;
+
+			fn encode(&self, _: &[u8]) -> usize;
+			fn finish(self) -> Self::Out;
+			fn verify(&self, _: &[u8]) -> Result<usize, Self::Error>;
+			fn name() -> &'static str;
+			fn chain(self, _: Self) -> Self;
+			fn boxed_name(self: Box<Self>) -> &'static str;
+			fn encode_all<'a, T: AsRef<[u8]> + 'a>(&self, _: &'a [T]) -> usize;
+			// `Self` is replaced in the bounds as well.
+			fn same_as<T>(&self, _: &T) -> bool where T: PartialEq<Self>;
+		}
+
+		trait Decoder;
+
+		// A bound can refer to any of the module's items, even with the full path.
+		fn encode_with<T>(_: &T, _: &[u8]) -> usize where T: Encoder;
+		fn decode_with<T, U>(_: &T, _: U) -> Vec<u8> where T: self::codec::Decoder, U: AsRef<[u8]>;
+		// Inline bounds are kept together, so a trait of the module's own can be mixed with others and a lifetime.
+		fn encode_owned<T: Clone + Encoder + 'static>(_: T) -> usize;
+		// That goes for the module's types too, even when they're on the left of a bound, and it can span several lines.
+		fn process<T>(_: T) -> Utf8
+		where
+			T: Into<Utf8>,
+			Utf8: From<T>;
+
+		// A type's error is used by all of its methods that don't declare their own.
+		#[error = "`Utf8` has to be constructable"]
+		type Utf8 {
+			fn new() -> Self;
+		}
+
+		// Only the implementation is checked, as the trait already says what's in it.
+		impl std::fmt::Display for Utf8;
+		// Unless the methods are named, which are checked through the trait, with `Self` being the type.
+		// A default passes just the same, so it can't make sure that the type overrides it.
+		impl Encoder for Utf8 {
+			fn finish(self) -> String;
+			fn chain(self, _: Self) -> Self;
+		}
+		// The trait's parameters can be concrete, or declared by the implementation, in which case it has to hold for all of them.
+		impl From<u32> for Utf8;
+		impl<T> From<T> for Frame<T> {
+			fn from(_: T) -> Self;
+		}
+	}
+}
+
+fn main() {
+}
@@ -0,0 +1,11 @@
+pub struct Config {
+	pub retries: u32,
+}
+
+impl Config {
+	pub fn new() -> Self {
+		Config {
+			retries: 3,
+		}
+	}
+}
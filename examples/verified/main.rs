@@ -0,0 +1,37 @@
+extern crate def_mod;
+
+use def_mod::def_mod;
+
+// Every module is declared the normal way, so the macro is only there to check them.
+mod config;
+
+#[cfg(unix)]
+#[path = "nix.rs"]
+mod sys;
+#[cfg(not(unix))]
+#[path = "other.rs"]
+mod sys;
+
+// Nothing is declared, so the invocation can be switched over without touching it, which is what `DEF_MOD_VERIFY_ONLY=1` does for the whole crate.
+// The paths are left out, but their cfgs still gate the checks, unlike `def_mod_check!`, which doesn't take them at all.
+def_mod! {
+	#![verify_only]
+
+	mod config {
+		type Config {
+			fn new() -> Self;
+			field retries: u32;
+		}
+	}
+
+	#[cfg(unix)] = "nix.rs"
+	#[cfg(not(unix))] = "other.rs"
+	mod sys {
+		fn name() -> &'static str;
+	}
+}
+
+fn main() {
+	assert_eq!(config::Config::new().retries, 3);
+	assert!(!sys::name().is_empty());
+}
@@ -0,0 +1,10 @@
+extern crate def_mod;
+
+mod outer;
+
+fn main() {
+	assert_eq!(outer::public::method(1), 1);
+	assert_eq!(outer::crate_visible::method(2), 2);
+	assert_eq!(outer::super_visible::method(3), 3);
+	assert_eq!(outer::restricted(4), 4);
+}
@@ -0,0 +1,33 @@
+use def_mod::def_mod;
+
+def_mod! {
+	// The visibility is given to each declaration of the module, while the load function stays private.
+	pub mod public {
+		fn method(_: u32) -> u32;
+
+		pub(super) mod inner {
+			pub(in crate::outer) fn method();
+			// This is relative to `inner`, so it's checked as if it were `pub(in super::super)`.
+			pub(in super::super) fn outer();
+		}
+	}
+
+	pub(crate) mod crate_visible {
+		fn method(_: u32) -> u32;
+	}
+
+	pub(super) mod super_visible {
+		fn method(_: u32) -> u32;
+	}
+
+	// Paths are relative to the directory of this file, just like they would be for `#[path]`.
+	#[cfg(unix)] = "outer/pathed/nix.rs"
+	#[cfg(not(unix))] = "outer/pathed/win.rs"
+	pub(in crate::outer) mod restricted {
+		fn method(_: u32) -> u32;
+	}
+}
+
+pub fn restricted(value: u32) -> u32 {
+	self::restricted::method(value)
+}
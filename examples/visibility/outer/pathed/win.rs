@@ -0,0 +1,3 @@
+pub fn method(value: u32) -> u32 {
+	value
+}
@@ -0,0 +1,5 @@
+pub(super) mod inner;
+
+pub fn method(value: u32) -> u32 {
+	value
+}
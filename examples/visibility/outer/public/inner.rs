@@ -0,0 +1,3 @@
+pub(in crate::outer) fn method() {}
+
+pub(in super::super) fn outer() {}
@@ -2,8 +2,10 @@
 `def_mod!` provides a familiar syntax to the standard module declarations, but with the added benefit
 of simpler implementation routing and statically verified module exports.
 
+It needs a nightly compiler, as that's where its diagnostics are emitted from, unless the `stable` feature is enabled, which is covered further down.
+
 ---
-```rust
+```rust,ignore
 extern crate def_mod;
 
 use def_mod::def_mod;
@@ -18,14 +20,250 @@ def_mod! {
     	// It will fail to compile if it finds none.
 		fn method(_: u32) -> u8;
 
+		// A custom error can be given, which is used when the signature doesn't match.
+		// The method still has to exist with the same number of parameters, otherwise you'll get the normal error.
+		// When it's declared on a type, it's used for all of the type's methods instead.
+		#[error = "other_method must be fn(u32) -> u8"]
+		fn other_method(_: u32) -> u8;
+
+		// A visibility can be given to check that the item is at least that visible.
+		// Only the item itself is checked, so a `pub` item inside of a private module still passes.
+		pub(crate) fn visible_method(_: u32) -> u8;
+
+		// A `const fn` is also checked to be callable in a const context.
+		// Each of its parameters has to be allowed in a const fn, as it's called by one.
+		const fn const_method(_: u32) -> u8;
+
+		// The names of the parameters are normally ignored, but `#[check_names]` makes each of them required.
+		// The method is then called through a wrapper that uses those names, so it's stricter about how it's declared.
+		// Do note that Rust doesn't keep the names as part of the function, so it can't compare them to the ones the module used.
+		#[check_names]
+		fn named_method(value: u32) -> u8;
+
+		// A `_` in place of a type leaves it to be inferred from the method, so this only checks the number of parameters.
+		// It can't be used where each type has to be named, which is a const or async method, one with `impl Trait`, or #[check_names].
+		fn loose_method(_, _) -> _;
+
+		// A pointer can't hold an `impl Trait`, so a method that uses one is called through a function with the same signature instead.
+		// It can be used in any of the parameters, and anywhere in the return type, except for an `async fn`, as the output has to be named.
+		// Much like a generic method, the unsafety of one with `impl Trait` parameters can't be checked.
+		// A call would happily turn a `&mut self` into a `&self`, so the receiver of such a method, or an async one, is checked on its own.
+		fn impl_method(_: impl Into<u32>) -> impl Iterator<Item = u8>;
+
+		// An `async fn` is checked by the future it returns, so any function that returns one with the same output will pass.
+		async fn async_method(_: u32) -> u8;
+
+		// An `unsafe fn` has to actually be unsafe, even though a safe one would coerce into the pointer just fine.
+		// If it isn't, the compiler will complain that it can't infer the type of `Marker` for `_assert_unsafe`.
+		unsafe fn unsafe_method(_: *const u8) -> u8;
+
+		// A pointer also accepts a method that's more general than it, such as one that doesn't tie its lifetimes together.
+		// With #[strict_lifetimes], each lifetime that's used more than once has to be used that way, otherwise the compiler can't infer `Marker` for `_assert_strict`.
+		// A method that returns a `'static` reference still passes, and it only works on a safe method without type parameters.
+		#[strict_lifetimes]
+		fn strict_method<'a>(_: &'a str, _: &'a str) -> &'a str;
+
+		// A higher-ranked parameter, like `for<'a> fn(&'a str) -> &'a str`, is kept as it's written, as is one in an `impl for<'a> Fn(&'a u8)`.
+		// So a method that needs one can't be declared with a parameter that only takes a single lifetime, like `fn(&'static str) -> &'static str`.
+		fn higher_ranked_method(_: for<'a> fn(&'a str) -> &'a str) -> usize;
+
+		// A foreign function can also be variadic, which is kept in the pointer that it's checked against.
+		unsafe extern "C" fn variadic_method(_: *const c_char, ...) -> c_int;
+
+		// A `#[must_use]` on a method is checked by calling it without using the result, which is denied as `unused_results` unless the method is `#[must_use]` too.
+		// It relies on how rustc lints an unused result, so the error is the lint's, and a result that's `#[must_use]` on its own, like a `Result`, always passes.
+		#[must_use]
+		fn must_use_method(_: u32) -> u8;
+
+		// A diverging function is checked like any other, so one that returns won't pass, and neither will the other way around.
+		fn diverging_method(_: i32) -> !;
+
+		// A few of them can be declared as a block instead, which gives each of them the ABI, and makes them unsafe, just like the module's own block would.
+		extern "C" {
+			fn foreign_method(_: c_int) -> c_int;
+			pub fn other_foreign_method() -> *const c_char;
+		}
+
 		// Much like the method declaration from above, this will check to see if a type was exported.
+		/// Doc comments can be written anywhere, but they're left out of the generated checks.
+		// The same goes for `#[deprecated]` and `#[inline]`, which only describe the item, while a `#[cfg]` or an `#[allow]` is kept.
+		// That's still the case when they're behind a `#[cfg_attr]`, which only keeps the attributes that aren't descriptive.
 		type MyStruct;
 
+		// The type can also be checked for the traits it implements.
+		type MyClonedStruct: Clone + Send + Debug;
+
+		// A single implementation can be checked too, which is handy when the trait has parameters.
+		// A generic implementation has to hold for all of its parameters, not just some of them.
+		impl From<u32> for MyStruct;
+		impl<T> From<T> for MyWrapper<T>;
+
+		// The methods that it has can be named too, which are checked through the trait, as `<MyStruct as Encoder>::finish`.
+		// A default passes just the same, as there's no telling whether the type overrides it, so this only makes sure that the signature is right.
+		impl Encoder for MyStruct {
+			fn finish(self) -> u8;
+		}
+
+		// An alias is checked to be the same type as its target, unlike the declarations above, which only need it to exist.
+		// It can't be declared both ways, unless each of them is gated by a different #[cfg].
+		type Handle = u64;
+
+		// The same goes for any other item, so a signature that differs between targets can be declared for each of them.
+		// Without cfgs to tell them apart, it's an error, as Rust doesn't have overloading, so only one of them could ever match.
+		#[cfg(target_pointer_width = "64")]
+		fn word_size() -> u64;
+		#[cfg(not(target_pointer_width = "64"))]
+		fn word_size() -> u32;
+
+		// A signature can be given a name, which functions that mirror each other can be declared with, including the ones in nested modules.
+		// It's the same as writing it out, with each of the parameters as `_: T`.
+		sig Transform = fn(&[u8]) -> Vec<u8>;
+		fn encode: Transform;
+		fn decode: Transform;
+
 		// Much like the normal method check, that functionality is also extended to types.
 		// So you can check if a type has a specific method exported.
 		type MyOtherStruct {
 			// This will check if this method exists on this type. (MyOtherStruct::method)
 			fn method(_: u32) -> u8;
+
+			// A receiver can be given a type too, like `self: Box<Self>` or `self: Pin<&mut Self>`, which has to match exactly.
+			fn boxed_method(self: Box<Self>) -> u8;
+
+			// `Self` is replaced by the type everywhere except inside of a macro's input, so `stringify!(Self)` is left as it is.
+			fn name(&self) -> [u8; stringify!(Self).len()];
+
+			// Public fields can be checked as well, the `field` keyword is optional.
+			field value: u32;
+
+			// As can associated constants.
+			const MAX: usize;
+
+			// Associated types need the trait that provides them.
+			// Without one, it's checked as an inherent associated type, which needs `#![feature(inherent_associated_types)]`.
+			type Iterator::Item;
+		}
+
+		// A single function can be checked without the block around it, by putting the type in front of its name.
+		// It's checked the same way as a function of the module, so it can't have a receiver, and it has to name the type instead of using `Self`.
+		fn MyOtherStruct::with_capacity(_: usize) -> MyOtherStruct;
+
+		// A tuple struct can be checked for its constructor, which fails if its fields are renamed, retyped or hidden.
+		type MyTupleStruct {
+			tuple(f64, u8);
+		}
+
+		// As can a unit struct, with `unit`.
+		type MyUnitStruct {
+			unit;
+		}
+
+		// Rust can't look at a type's `#[repr]`, so the `#[repr(C)]` here only describes it, and is left out of the checks.
+		// What can be checked is its layout, the size and alignment are asserted when the module is compiled, which catches most changes to its fields.
+		// It's not the same as checking the representation, as a type can have the same layout without it, and a generic type's layout can't be checked at all.
+		#[repr(C)]
+		type MyPacket {
+			size = 16, align = 4
+		}
+
+		// They can be given as attributes too, which works on a type that isn't declared with a body, like a marker that has to be zero-sized.
+		#[size = 0]
+		type MyMarker;
+
+		// An operator is checked against the trait's method from `std::ops`, so an inherent method of the same name won't pass.
+		// The parameters are only types, with the receiver written like a method's, and the second one is the trait's parameter, unless it's given: `op Mul<f32>`.
+		type MyVector {
+			op Add(self, Self) -> Self;
+			op Mul(self, f32) -> Self;
+			op Index(&self, usize) -> &f32;
+		}
+
+		// A method can also be called at runtime by a test, which is generated with `#[smoke_test]`, and run by `cargo test`.
+		// Each parameter is given its default value, so it can't have a receiver, or be generic, async or unsafe.
+		type MySmokedStruct {
+			#[smoke_test]
+			fn new() -> Self;
+		}
+
+		// Types can also declare generic parameters, which are then used to check everything inside of it.
+		// A method's `where` clause adds to the type's bounds, while one on a type that's already known, like `where Self: Sized`, is left out.
+		type MyWrapper<T: Clone> {
+			fn get(&self) -> &T;
+			fn take(&mut self) -> T where T: Default;
+		}
+
+		// The type is named with those parameters even when it has nothing inside, so the real one can't need more than `T: Clone + 'static`, but it can need less.
+		type MyHolder<T: Clone + 'static>;
+
+		// A default is checked by naming the type without it, which has to be the same type as naming it with the default.
+		// That also goes for an alias, but it's an error anywhere else, as a function's parameters can't have one.
+		type MyBuffer<T = u8> {
+			fn len(&self) -> usize;
+		}
+
+		// Const parameters are declared the same way, on both types and methods.
+		type MyArray<const N: usize> {
+			fn first<const M: usize>(&self) -> [u8; M];
+		}
+
+		// If you only care that something is reachable from the module, and not where it's defined, it can be declared with `use`.
+		// `use fn` makes sure it's a value, like a function, while a plain `use` will accept anything that can be imported.
+		use fn reexported_method;
+		use ReexportedStruct;
+
+		// A macro can be checked as long as it can be named by a path, which only the name is checked against.
+		// So a `macro_rules!` has to be re-exported by the module, `pub(crate) use my_macro;`, and a `#[macro_export]` one is at the crate's root instead.
+		macro my_macro;
+
+		// Constants and statics can also be checked, as long as their types match.
+		const PAGE_SIZE: usize;
+		static COUNTER: AtomicUsize;
+		// A mutable static has to be declared as one, and a static that's declared without `mut` can't be mutable either.
+		static mut LAST_ERROR: i32;
+		// That includes a function pointer that's stored in one, which is how a handler can be checked for its signature.
+		const HANDLER: fn(u8) -> u8;
+		// A function that's handed out through a const can be checked along with it, which is the same as declaring both.
+		fn handler(_: u8) -> u8 as const HANDLER;
+
+		// Traits can be checked too, the methods are verified against anything that implements it.
+		trait MyTrait {
+			// An associated type has to have at least the declared bounds, and `Self::Output` is the trait's own.
+			type Output: Clone;
+
+			fn method(&self, _: u32) -> u8;
+			fn output(&self) -> Self::Output;
+		}
+
+		// A where clause can name anything that the module exports, as well as `Self` inside of a type or trait.
+		// That includes the type on the left of a bound, as everything is imported, unless the module is #[no_glob], where it needs its path.
+		fn run<T>(_: T) where T: MyTrait;
+		fn wrap<T>(_: T) -> MyStruct
+		where
+			T: Into<MyStruct>,
+			MyStruct: From<T>;
+
+		// A path that starts with `super` is relative to the module, just like in its source, so it can name the module's siblings.
+		// Any other path is relative to where `def_mod!` was invoked, so `my_mod::MyStruct` would work as well.
+		fn process(_: super::my_mod::MyStruct);
+
+		// Enums can be checked for their variants, including the types of their fields.
+		enum MyEnum {
+			Tuple(u8),
+			Struct { x: u32, y: u32 },
+			Unit,
+		}
+
+		// As can unions for their fields, which are read from inside of an `unsafe` block.
+		// Each field is also used to build the union on its own, so a struct with more than one field won't pass.
+		union MyUnion {
+			word: u32,
+			bytes: [u8; 4],
+		}
+
+		// Submodules can be checked as well, they're declared by the module's own source, so only their exports are verified.
+		// Any attributes are used to gate the checks, much like a path would for the declarations.
+		mod my_submod {
+			fn method(_: u32) -> u8;
 		}
 	}
 
@@ -33,6 +271,113 @@ def_mod! {
 	#[cfg(windows)]
 	mod my_second_mod;
 
+	// Modules that share a cfg can be grouped in a block, which gives the cfg to each of them, on top of their own.
+	cfg(feature = "advanced") {
+		mod my_advanced_mod;
+		mod my_other_advanced_mod {
+			fn method(_: u32) -> u8;
+		}
+	}
+
+	// A trait can be generated from the functions that the module declares, each of them taking `&self`.
+	// Type methods are included as long as they don't have a receiver, with `Self` being replaced by the type.
+	// This gives you a named contract that can be implemented by something else, like a mock.
+	#[as_trait(MyModApi)]
+	mod my_api_mod {
+		fn plus_one(_: u8) -> u8;
+	}
+
+	// Expands to (along with the usual checks):
+
+	use self::_as_trait_my_api_mod::MyModApi;
+	mod _as_trait_my_api_mod {
+		use super::*;
+		use super::my_api_mod::*;
+
+		pub trait MyModApi {
+			fn plus_one(&self, _: u8) -> u8;
+		}
+	}
+
+	// With #[object_safe], the functions are the methods of a trait the module exports, and each one is checked to be callable on a `dyn MyPlugin`.
+	// That fails unless the trait is object safe, and a method that takes `self` by value, or doesn't take it at all, is an error.
+	#[object_safe(MyPlugin)]
+	mod my_plugin_mod {
+		fn name(&self) -> &'static str;
+		fn run(&mut self, _: u32) -> bool;
+	}
+
+	// There's no way to list everything that a module exports, so it can't be checked for anything extra.
+	// Instead, the names that it mustn't export can be sealed, and it's an error (`is ambiguous`) if the module has one of them.
+	// This also works on submodules.
+	#[sealed(legacy_method, LegacyStruct)]
+	mod my_sealed_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// The checks are qualified with `self`, as the module is declared where `def_mod!` is invoked.
+	// If it's declared somewhere else, a root can be given instead, and the module won't be declared again.
+	// A relative root starts from where `def_mod!` is invoked, so an extern crate needs the leading `::`.
+	#[root = "crate::external"]
+	mod my_external_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// The checks are put in a function called `_load_` followed by the module's name, which can be changed if it clashes with another.
+	// An empty body, `mod my_mod {}`, has no checks to put in one, so it's left out, unless it's named, or the module has a #[root] to import it from.
+	#[load_fn = "_load_my_renamed_mod"]
+	mod my_renamed_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// Everything else that's generated starts with `_` too, like `_ASSERT_METHOD_0`, which #[prefix] replaces, load function included, unless it's named.
+	// The only names it leaves alone are the parameters', like `_self`, as they're only seen inside of the checks.
+	// Its submodules inherit it, so everything the module generates can be told apart by the prefix.
+	#[prefix = "__dm_"]
+	mod my_prefixed_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// Everything that the module exports is imported into the checks, so a name like `Result` can shadow the prelude's in the signatures.
+	// With #[no_glob], nothing is imported, and the declared types are named by their path instead, so `Result` is still the prelude's.
+	// Anything else from the module has to be named by its path too, and its submodules inherit it.
+	// Declaring an item with the name of one in the prelude, like `drop` or `Result`, warns about that, unless the module is #[no_glob].
+	#[no_glob]
+	mod my_unglobbed_mod {
+		type Result {
+			fn parse(_: &str) -> Result<Self, ParseIntError>;
+		}
+		fn check(_: &crate::my_unglobbed_mod::Result) -> bool;
+	}
+
+	// A method's pointer is assigned to a const, unless #[assert_style = "let"] is given, which binds it inside of the load function instead.
+	// Either one catches a mismatch, but a const is an item of its own, which is what the error will point to.
+	#[assert_style = "let"]
+	mod my_bound_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// With #[no_std], any signature that names a type which needs std, such as `String`, `Vec`, or a path through `std`, is warned about.
+	// It only goes by the name, and the types can be given instead, like #[no_std(String, HashMap)], which replaces the default ones.
+	#[no_std]
+	mod my_embedded_mod {
+		fn checksum(_: &[u8]) -> u32;
+	}
+
+	// Any other attribute goes on each declaration of the module, but it can be targeted at only the declarations, or only the load function.
+	// A #[cfg] can't be targeted, as it has to gate both of them.
+	#[mod: doc(hidden)]
+	#[load: allow(unused)]
+	mod my_targeted_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	// The visibility of a module is given to each of its declarations, but the load function is always private.
+	// Any restriction is fine, as long as the module is still visible from where `def_mod!` is invoked.
+	pub(super) mod my_restricted_mod {
+		pub(in super::super) fn method(_: u32) -> u8;
+	}
+
 	// When declaring an attribute, you can optionally add a string literal.
 	// This literal is used as the path attribute for the module file.
 	// All attributes declared with a path are treated as _mutually exclusive_.
@@ -54,8 +399,66 @@ def_mod! {
 	#[path = "sys/nix/mod.rs"]
 	mod sys;
 
+	// A path that doesn't exist is reported at the literal, instead of at the declaration.
+	// The error is gated by the same attribute, so only the path of the target that's being built has to exist.
+
+	// If none of the attributes are active, the module isn't declared, and neither are its checks or its #[as_trait].
+	// If more than one is, it'd be declared twice, so that's an error, which points at the path of the later one.
+
+	// A path can also refer to the path of an earlier attribute, by prefixing its key with `~`.
+	// `#[cfg(unix)]` has the key `unix`, while `#[cfg(target_os = "macos")]` has the key `macos`.
+	#[cfg(unix)] = "platform/nix.rs"
+	#[cfg(not(unix))] = "~unix"
+	mod platform;
+
+	// The path can also be read from an environment variable while the macro is expanded, which is handy for generated code.
+	// It's an error if the variable isn't set.
+	#[cfg(feature = "gen")] = env!("MY_GEN_PATH")
+	mod generated;
+
+	// A list of paths can be given as well, and the first one that exists is used.
+	// It's an error if none of them exist.
+	// Every path is checked against the directory of the file that `def_mod!` is in, so it's best not to invoke it from an inline module.
+	#[cfg(unix)] = ["vendor/sys.rs", "sys.rs"]
+	mod vendored;
+
+	// If the module is always at that path, `#[path_env]` can be used instead.
+	#[path_env = "MY_GEN_PATH"]
+	mod always_generated;
+
+	// `linux`, `macos` and `windows` can be used as a shorthand for their `target_os`.
+	#[linux = "sys/nix/mod.rs"]
+	#[macos = "~linux"]
+	#[windows = "sys/win/mod.rs"]
+	mod shorthand;
+
+	// Expands to:
+
+	#[cfg(target_os = "linux")]
+	#[path = "sys/nix/mod.rs"]
+	mod shorthand;
+
+	#[cfg(target_os = "macos")]
+	#[path = "sys/nix/mod.rs"]
+	mod shorthand;
+
+	#[cfg(target_os = "windows")]
+	#[path = "sys/win/mod.rs"]
+	mod shorthand;
+
+	// The same module can be declared more than once, as long as each declaration is gated by a different cfg.
+	// Anything else is an error, as it would only end up being defined twice.
+	#[cfg(unix)] = "sys/nix/mod.rs"
+	mod native {
+		fn method(_: u32) -> u8;
+	}
+	#[cfg(not(unix))] = "sys/win/mod.rs"
+	mod native {
+		fn other_method(_: u32) -> u8;
+	}
+
 	// You can also declare attributes on methods or types themselves, and they will be used when verifying the type.
-	// This module itself will be verified when not on a windows system.
+	// This module itself will be verified when not on a windows system, as its `cfg` is copied to the checks.
 	#[cfg(not(windows))]
 	mod my_third_mod {
 		// This method will only be verified when on linux.
@@ -84,11 +487,43 @@ It's entirely up to you.
 
 ---
 
+With the `manifest` feature, an invocation can also write out everything it declared as JSON, so the surface that's checked can be compared across commits.  
+The path is given by an attribute at the start of the invocation, or by the `DEF_MOD_MANIFEST` environment variable, and is relative to where the compiler runs.  
+Each invocation has its own entry, keyed by its file and line, so any number of them can share the same file.  
+
+```rust,ignore
+def_mod! {
+	#![manifest = "target/def_mod.json"]
+
+	mod my_mod {
+		fn method(_: u32) -> u8;
+	}
+}
+```
+
+To find an invocation that's slow to expand, set `DEF_MOD_TRACE=1`, and each module prints where it is, how many checks it declared, and how long they took to generate.  
+The checks include the items inside of its types, traits and submodules, and nothing is printed without the variable.  
+
+```text
+def_mod: src/lib.rs:12 mod sys, checks: 14, took 1.2ms
+```
+
+The diagnostics are emitted through an API that's only on nightly, so that's what the crate needs by default.  
+With the `stable` feature, it builds on stable instead, where each error is a `compile_error!`, and each warning is the use of a deprecated item.  
+They point at the same spans, though a warning reads as a deprecation, and the environment variables aren't tracked, so changing one won't expand the macro again.  
+
+```toml
+[dependencies]
+def-mod = { version = "0.5", features = ["stable"] }
+```
+
+---
+
 In case you're curious as to what the macro generates:
 
 A method assertion is transformed to something like:
 
-```rust
+```rust,ignore
 fn method(_: u32) -> u8;
 
 // into
@@ -96,15 +531,37 @@ fn method(_: u32) -> u8;
 const _VALUE: fn(u32) -> u8 = my_mod::method;
 ```
 
+The pointer keeps the method's ABI, and a function only ever coerces into a pointer with the exact same ABI.  
+So `extern "C" fn callback();` won't accept a plain `fn callback()`, or even an `extern "system"` one, and the other way around.  
+Unsafety is the exception, as a safe function coerces into an `unsafe` pointer just fine, which is why it's checked separately.  
+Async methods, and the ones with `impl Trait`, are called through a function instead, so their ABI isn't checked.
+
+```rust,compile_fail
+use def_mod::def_mod;
+
+mod ffi {
+	pub fn callback() {}
+}
+
+def_mod! {
+	// The module is already declared, so it's only checked.
+	#[root = "self"]
+	mod ffi {
+		extern "C" fn callback();
+	}
+}
+# fn main() {}
+```
+
 A method assertion with generics is a bit more complex:
 
-```rust
+```rust,ignore
 fn generic<'a , T: 'a>(_: u32, _: T, _: fn(T) -> T) -> &'a T;
 
 // will turn into into (Note: This is nested inside of the load function itself.)
 
 #[allow(non_snake_case)]
-fn _load_module_name_generic<'a, T: 'a>() {
+fn _load_module_name_generic_0<'a, T: 'a>() {
 	let _VALUE:
 			fn(_: u32, _: T,
 			   _: fn(T) -> T) -> &'a T =
@@ -112,9 +569,41 @@ fn _load_module_name_generic<'a, T: 'a>() {
 }
 ```
 
+A constant assertion is done in much the same way, while a static one only borrows the value, or takes a pointer to it if it's mutable:
+
+```rust,ignore
+const PAGE_SIZE: usize;
+static COUNTER: AtomicUsize;
+static mut LAST_ERROR: i32;
+
+// into
+
+const _ASSERT_CONST_0: usize = self::my_mod::PAGE_SIZE;
+let _: &AtomicUsize = &self::my_mod::COUNTER;
+let _: *mut i32 = ::core::ptr::addr_of_mut!(self::my_mod::LAST_ERROR);
+```
+
+A trait assertion uses a generic function, with the trait being the bound:
+
+```rust,ignore
+trait MyTrait {
+	fn method(&self, _: u32) -> u8;
+}
+
+// into
+
+{
+	use self::my_mod::MyTrait;
+	#[allow(non_snake_case)]
+	fn _check_MyTrait<_Self: MyTrait>() {
+		let _ASSERT_METHOD_0: fn(_self: &_Self, _: u32) -> u8 = _Self::method;
+	}
+}
+```
+
 A type assertion is transformed into a new scope with a use declaration:
 
-```rust
+```rust,ignore
 def_mod! {
 	mod my_mod {
 		type Test;
@@ -129,11 +618,13 @@ def_mod! {
 }
 ```
 
+If you want to see exactly what's generated, swap `def_mod!` for `def_mod_debug!`, which prints it while compiling, laid out with an item to a line by `def_mod_core::render_tokens`.
+
 All of those are shoved into a function generated by the macro.
 
 For example, given something like:
 
-```rust
+```rust,ignore
 def_mod! {
 	mod my_mod {
 		fn plus_one(value: u8) -> u8;
@@ -144,6 +635,8 @@ def_mod! {
 			fn clear(&mut self);
 		}
 
+		fn same<'a>(_: &'a u8) -> &'a u8;
+
 		fn generic<'a , T: 'a>(_: u32, _: T, _: fn(T) -> T) -> &'a T;
 	}
 }
@@ -151,7 +644,7 @@ def_mod! {
 
 It'll turn it into something like this:
 
-```rust
+```rust,ignore
 mod my_mod;
 
 fn _load_my_mod() {
@@ -162,515 +655,84 @@ fn _load_my_mod() {
 		const _ASSERT_METHOD_2: fn(_self: &MyStruct) -> MyStruct = MyStruct::dupe;
 		const _ASSERT_METHOD_3: fn(_self: &mut MyStruct) -> MyStruct = MyStruct::clear;
 	}
+	// Lifetimes on their own are bound by the pointer, so they don't need a function.
+	const _ASSERT_METHOD_4: for<'a> fn(_: &'a u8) -> &'a u8 = self::my_mod::same;
+	// Anything else does, including a lifetime with a bound, as the pointer can't declare it.
 	#[allow(non_snake_case)]
-    fn _load_my_mod_generic<'a, T: 'a>() {
-    	let _ASSERT_METHOD_4:
+    fn _load_my_mod_generic_5<'a, T: 'a>() {
+    	let _ASSERT_METHOD_5:
     			fn(_: u32, _: T,
     			   _: fn(T) -> T) -> &'a T =
     		my_mod::generic;
     }
 }
 ```
-*/
-
-#![feature(proc_macro_diagnostic)]
-
-#[macro_use]
-extern crate proc_macro;
-
-#[macro_use]
-extern crate proc_macro2;
 
-#[macro_use]
-extern crate quote;
+If a module has to be declared by hand, say because its path is picked by a `cfg_attr`, `def_mod_check!` takes the same body but only generates the checks.
+Each module is checked as if it had `#[root = "self"]`, so a path can't be given to it, but a plain `#[cfg(..)]` still gates the checks.
 
-#[macro_use]
-extern crate syn;
+```rust,ignore
+#[cfg_attr(unix, path = "nix.rs")]
+#[cfg_attr(not(unix), path = "other.rs")]
+mod sys;
 
-use proc_macro::TokenStream as TStream;
-
-use proc_macro2::{TokenStream, TokenTree, Group};
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::*;
-use syn::punctuated::Punctuated;
-use syn::spanned::Spanned;
-use syn::synom::{Parser, Synom};
-
-#[proc_macro]
-pub fn def_mod(tokens: TStream) -> TStream {
-	let t = ModuleDecl::parse_all;
-	let declarations: Vec<ModuleDecl> = t.parse(tokens).unwrap();
-
-	let mut output = TokenStream::new();
-
-	for module in declarations {
-		let mut pathed_attrs = vec![];
-		let mut custom_attrs = vec![];
-		// Group the attributes that were declared with a path value.
-		for attr in module.attrs {
-			if let (attr, Some(path)) = attr {
-				pathed_attrs.push((attr, path));
-			} else {
-				custom_attrs.push(attr.0);
-			};
-		}
-		// Ghost the attr vectors, so no one can change them...
-		let pathed_attrs = &pathed_attrs;
-		let custom_attrs = &custom_attrs;
-
-		let vis = &module.vis;
-		let mod_token = &module.mod_token;
-		let module_name = &module.ident;
-
-		if pathed_attrs.is_empty() {
-			let t = quote_spanned! { module_name.span() =>
-				#(#custom_attrs)*
-				#vis #mod_token #module_name;
-			};
-			t.to_tokens(&mut output);
-		} else {
-			// If there were some pathed attrs, then we need to generate an equal number of mod decls with the attrs.
-			for (attr, path) in pathed_attrs {
-				let t = quote_spanned! { module_name.span() =>
-					#attr
-					#[path=#path]
-					#(#custom_attrs)*
-					#vis #mod_token #module_name;
-				};
-				t.to_tokens(&mut output);
-			}
-		}
-
-		// Generate a load function, if the module was declared with some items.
-		if let ModuleBody::Content((_brace, body)) = module.body {
-			let mut index: u32 = 0;
-			// This is the function that transforms a method into an assertion.
-			let mut tokenise_method = |type_name: Option<&Ident>, method_item: TraitItemMethod| {
-				if let Some(body) = method_item.default {
-					body.span()
-						.unstable()
-						.error("A body isn't valid here.")
-						.emit();
-					return TokenStream::new();
-				}
-				let mapping = if let Some(type_name) = type_name {
-					let self_replacement = type_name.to_string();
-					let func = move |ident: Ident| {
-						// @FIXME Jezza - 21 Dec. 2018: Yeah, this is very... eh... yucky...
-						// I can't think of a better way to do this...
-						if ident.to_string() == "Self" {
-							Ident::new(&self_replacement, ident.span())
-						} else {
-							ident
-						}
-					};
-					Some(func)
-				} else {
-					None
-				};
-				let t = convert(module_name, type_name, index, method_item, mapping);
-				index += 1;
-				t
-			};
-			let items: Vec<TokenStream> = body.into_iter()
-				.map(|item| {
-					// Transform each item into the corresponding check.
-					match item {
-						DeclItem::Method(method_item) => tokenise_method(None, method_item),
-						DeclItem::Type(type_item) => {
-							let attrs = &type_item.attrs;
-							let type_name = &type_item.ident;
-
-							let method_items = if let TypeDeclBody::Content((_brace, body)) = type_item.body {
-								body.into_iter()
-									.map(|method_item| tokenise_method(Some(type_name), method_item))
-									.collect()
-							} else {
-								vec![]
-							};
-
-							// We use the actual use declaration here to test for the type itself, as it'll fail if it doesn't exist or not exported.
-							// It also makes the codegen easier, because we don't have to qualify the full name type.
-							quote! {
-								#(#attrs)*
-								{
-									use self::#module_name::#type_name;
-									#(#method_items)*
-								}
-							}
-						}
-					}
-				})
-				.filter(|t| !t.is_empty())
-				.collect();
-
-			let function_name = {
-				let name = format!("_load_{}", module_name);
-				Ident::new(&name, module_name.span())
-			};
-			let t = quote! {
-				#[allow(dead_code)]
-				fn #function_name() {
-					use self::#module_name::*;
-					#(#items)*
-				}
-			};
-			t.to_tokens(&mut output);
-		}
+def_mod_check! {
+	#[expect_cfg(any(unix, windows))]
+	mod sys {
+		fn name() -> &'static str;
 	}
-	output.into()
-}
-
-///
-/// A module declaration: `mod my_mod`
-/// 
-/// One of the differences between this and a normal mod decl is
-/// the attributes can declare a path literal:
-/// ```rust
-/// #[cfg(target_os = "windows")] = "my_mod/win/mod.rs"
-/// mod my_mod;
-/// ```
-/// 
-/// The other is that it can declare a body.
-/// The body contains methods/types that the module needs to export.
-/// If the module doesn't export those symbols, you will get a compiler error.
-/// 
-#[cfg_attr(feature = "derive-debug", derive(Debug))]
-struct ModuleDecl {
-	attrs: Vec<(Attribute, Option<LitStr>)>,
-	vis: Visibility,
-	mod_token: Token![mod],
-	ident: Ident,
-	body: ModuleBody,
 }
+```
 
-#[cfg_attr(feature = "derive-debug", derive(Debug))]
-enum ModuleBody {
-	Content((token::Brace, Vec<DeclItem>)),
-	Terminated(Token![;]),
-}
+A module that's declared by hand can't be checked for its own attributes, like `#![deny(unsafe_code)]`, as they're in a file that the macro doesn't read.
+What can be checked is that the checks themselves are generated, which `#[expect_cfg(..)]` makes sure of, by failing to compile if any of its cfgs aren't active.
+It's gated by the module's own `#[cfg]`, and works with `def_mod!` too.
 
-impl ModuleDecl {
-	named!(parse_all -> Vec<ModuleDecl>, do_parse!(
-		decls: many0!(syn!(ModuleDecl)) >>
-		(decls)
-	));
-}
+If every module is declared by hand, and the macro is only there as a lint, `#![verify_only]` at the start of the invocation switches `def_mod!` over to only generating the checks.
+Unlike `def_mod_check!`, the paths can stay where they are, as they're left out, but their cfgs still gate the checks, so the same invocation works either way.
+Setting `DEF_MOD_VERIFY_ONLY=1` does the same for every invocation in the crate, say from the `[env]` of a `.cargo/config.toml`.
 
-impl Synom for ModuleDecl {
-	named!(parse -> Self, do_parse!(
-		attrs: many0!(do_parse!(
-			attr: call!(Attribute::parse_outer) >>
-			eq: option!(punct!(=)) >>
-			path: cond!(eq.is_some(), syn!(LitStr))>>
-			(attr, path)
-		)) >>
-		vis: syn!(Visibility) >>
-		mod_token: keyword!(mod) >>
-		ident: syn!(Ident) >>
-		body: alt! (
-			punct!(;) => { ModuleBody::Terminated }
-			|
-			braces!(many0!(DeclItem::parse)) => { ModuleBody::Content }
-		) >>
-		(ModuleDecl {
-			attrs,
-			vis,
-			mod_token,
-			ident,
-			body,
-		})
-	));
-}
+```rust,ignore
+def_mod! {
+	#![verify_only]
 
-impl ToTokens for ModuleDecl {
-	fn to_tokens(&self, tokens: &mut TokenStream) {
-		self.vis.to_tokens(tokens);
+	#[cfg(unix)] = "nix.rs"
+	#[cfg(not(unix))] = "other.rs"
+	mod sys {
+		fn name() -> &'static str;
 	}
 }
+```
 
-#[cfg_attr(feature = "derive-debug", derive(Debug))]
-enum DeclItem {
-	Method(TraitItemMethod),
-	Type(TypeDecl),
-}
+The parsing and generation are in `def-mod-core`, which other proc macros can depend on to generate the same checks.
+Its `expand` is exactly what the macros call, and a `ModuleDecl` from its `ast` module can be parsed, or put together item by item, and turned into tokens with `to_token_stream()`.
+A single method can be checked with `convert`, against a module that's declared some other way.
+With its `testing` feature, `render` lays those tokens out as source, an item to a line, so what a module generates can be compared against a file in a test.
+*/
 
-#[cfg_attr(feature = "derive-debug", derive(Debug))]
-struct TypeDecl {
-	attrs: Vec<Attribute>,
-	ident: Ident,
-	body: TypeDeclBody,
-}
+#![allow(clippy::tabs_in_doc_comments)]
 
-#[cfg_attr(feature = "derive-debug", derive(Debug))]
-enum TypeDeclBody {
-	Content((token::Brace, Vec<TraitItemMethod>)),
-	Terminated(Token![;]),
-}
+extern crate def_mod_core;
+extern crate proc_macro;
 
-impl DeclItem {
-	named!(parse -> Self, alt!(
-		syn!(TraitItemMethod) => { DeclItem::Method }
-		|
-		syn!(TypeDecl) => { DeclItem::Type }
-	));
-}
+use proc_macro::TokenStream as TStream;
 
-impl Synom for TypeDecl {
-	named!(parse -> Self, do_parse!(
-			attrs: many0!(Attribute::parse_outer) >>
-			_type: keyword!(type) >>
-			ident: syn!(Ident) >>
-			body: alt!(
-				punct!(;) => { TypeDeclBody::Terminated }
-				|
-				braces!(many0!(TraitItemMethod::parse)) => { TypeDeclBody::Content }
-			) >>
-			(TypeDecl {
-				attrs,
-				ident,
-				body,
-			})
-		)
-	);
+#[proc_macro]
+pub fn def_mod(tokens: TStream) -> TStream {
+	def_mod_core::expand(tokens.into(), true).into()
 }
 
-fn convert<F>(module_name: &Ident, type_name: Option<&Ident>, index: u32, method_item: TraitItemMethod, ident_mapping: Option<F>) -> TokenStream
-		where F: Fn(Ident) -> Ident {
-
-//	println!("Context: {}", context);
-//	println!("Sig: {:?}", sig);
-//	pub struct MethodSig {
-//		pub constness: Option<Token![const]>,
-//		pub unsafety: Option<Token![unsafe]>,
-//		pub abi: Option<Abi>,
-//		pub ident: Ident,
-//		pub decl: FnDecl,
-//	}
-//	pub struct FnDecl {
-//		pub fn_token: Token![fn],
-//		pub generics: Generics,
-//		pub paren_token: token::Paren,
-//		pub inputs: Punctuated<FnArg, Token![,]>,
-//		pub variadic: Option<Token![...]>,
-//		pub output: ReturnType,
-//	}
-//	pub enum FnArg {
-//		/// Self captured by reference in a function signature: `&self` or `&mut
-//		/// self`.
-//		///
-//		/// *This type is available if Syn is built with the `"full"` feature.*
-//		pub SelfRef(ArgSelfRef {
-//			pub and_token: Token![&],
-//			pub lifetime: Option<Lifetime>,
-//			pub mutability: Option<Token![mut]>,
-//			pub self_token: Token![self],
-//		}),
-//	
-//		/// Self captured by value in a function signature: `self` or `mut
-//		/// self`.
-//		///
-//		/// *This type is available if Syn is built with the `"full"` feature.*
-//		pub SelfValue(ArgSelf {
-//			pub mutability: Option<Token![mut]>,
-//			pub self_token: Token![self],
-//		}),
-//	
-//		/// An explicitly typed pattern captured by a function signature.
-//		///
-//		/// *This type is available if Syn is built with the `"full"` feature.*
-//		pub Captured(ArgCaptured {
-//			pub pat: Pat,
-//			pub colon_token: Token![:],
-//			pub ty: Type,
-//		}),
-//	
-//		/// A pattern whose type is inferred captured by a function signature.
-//		pub Inferred(Pat),
-//		/// A type not bound to any pattern in a function signature.
-//		pub Ignored(Type),
-//	}
-//	pub struct Generics {
-//		pub lt_token: Option<Token![<]>,
-//		pub params: Punctuated<GenericParam, Token![,]>,
-//		pub gt_token: Option<Token![>]>,
-//		pub where_clause: Option<WhereClause>,
-//	}
-	let MethodSig {
-		constness: _,
-		unsafety,
-		abi,
-		ident,
-		decl,
-	} = method_item.sig;
-
-	let FnDecl {
-		fn_token,
-		generics,
-		paren_token,
-		inputs,
-		variadic,
-		output,
-	} = decl;
-
-	let inputs = {
-		let mut values = Punctuated::new();
-		for arg in inputs {
-			let bare_fn_arg = match arg {
-				FnArg::SelfRef(ArgSelfRef{
-					and_token,
-					lifetime,
-					mutability,
-					self_token: _
-				}) => {
-					let t = quote! {
-						_self: #and_token #lifetime #mutability #type_name
-					};
-					parse2::<BareFnArg>(t).expect("Should never happen [self-ref]")
-				}
-				FnArg::SelfValue(ArgSelf {
-					mutability,
-					self_token: _,
-				}) => {
-					let t = quote! {
-						_self: #mutability #type_name
-					};
-					parse2::<BareFnArg>(t).expect("Should never happen [self-value]")
-				}
-				FnArg::Captured(ArgCaptured {
-					pat,
-					colon_token,
-					ty,
-				}) => {
-					let ts = ty.into_token_stream();
-					let ty = if let Some(ref func) = ident_mapping {
-						replace_idents(ts, func)
-					} else {
-						ts
-					};
-					let t: TokenStream = quote! {
-						#pat #colon_token #ty
-					};
-					parse2::<BareFnArg>(t).expect("Should never happen [arg-captured]")
-				}
-				FnArg::Inferred(pat) => {
-					// Technically, this shouldn't be possible, but we know the transformation that needs to take place,
-					// so I just go ahead and do that.
-					let t = quote! {
-						#pat: _
-					};
-					parse2::<BareFnArg>(t).expect("Should never happen [inferred]")
-				}
-				FnArg::Ignored(ty) => {
-					// This way of writing signatures has been deprecated, and I should probably emit a warning.
-					ident.span()
-						.unstable()
-						.warning("Declaring parameters without a name is deprecated, and will not be supported in the future. [Hint: Just add \"_:\" to the parameter to remove this warning...]")
-						.emit();
-					let ts = ty.into_token_stream();
-					let ts = if let Some(ref func) = ident_mapping {
-						replace_idents(ts, func)
-					} else {
-						ts
-					};
-					parse2::<BareFnArg>(ts).expect("Should never happen [ignored]")
-				}
-			};
-			values.push(bare_fn_arg);
-		}
-		values
-	};
-
-	let output = {
-		let ts = output.into_token_stream();
-		let ts = if let Some(ref func) = ident_mapping {
-			replace_idents(ts, func)
-		} else {
-			ts
-		};
-		parse2::<ReturnType>(ts).expect("Should never happen [return-type]")
-	};
-
-	let type_bare_fn = TypeBareFn {
-		unsafety,
-		abi,
-		fn_token,
-		lifetimes: None,
-		paren_token,
-		inputs,
-		variadic,
-		output,
-	};
-
-//	TypeBareFn {
-//		=pub unsafety: Option<Token![unsafe]>,
-//		=pub abi: Option<Abi>,
-//		=pub fn_token: Token![fn],
-//		~pub lifetimes: Option<BoundLifetimes>,
-//		=pub paren_token: token::Paren,
-//		~pub inputs: Punctuated<BareFnArg, Token![,]>,
-//		=pub variadic: Option<Token![...]>,
-//		=pub output: ReturnType,
-//	}
-//	pub struct BareFnArg {
-//		pub name: Option<(BareFnArgName, Token![:])>,
-//		pub ty: Type,
-//	}
-//	pub enum BareFnArgName {
-//		/// Argument given a name.
-//		Named(Ident),
-//		/// Argument not given a name, matched with `_`.
-//		Wild(Token![_]),
-//	}
-	let attrs = &method_item.attrs;
-	let load_ident = {
-		let name = format!("_ASSERT_METHOD_{}", index);
-		Ident::new(&name, ident.span())
-	};
-	let context = type_name.unwrap_or(module_name);
-
-	if generics.params.is_empty() {
-		quote! {
-			#(#attrs)*
-			const #load_ident: #type_bare_fn = #context::#ident;
-		}
-	} else {
-		let nested_function_name = {
-			let name = if let Some(type_name) = type_name {
-				format!("_load_{}_{}_{}", module_name, type_name, ident)
-			} else {
-				format!("_load_{}_{}", module_name, ident)
-			};
-			Ident::new(&name, ident.span())
-		};
-		// Do note that we don't use ty_generics, as it's just the use-site, which for us is in the method's signature.
-		let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
-		quote! {
-			#(#attrs)*
-			#[allow(non_snake_case)]
-			fn #nested_function_name #impl_generics() #where_clause {
-				let #load_ident: #type_bare_fn = #context::#ident;
-			}
-		}
-	}
+/// Exactly the same as `def_mod!`, but the generated code is also printed while compiling, laid out with an item to a line.
+#[proc_macro]
+pub fn def_mod_debug(tokens: TStream) -> TStream {
+	let output = def_mod_core::expand(tokens.into(), true);
+	eprint!("{}", def_mod_core::render_tokens(output.clone()));
+	output.into()
 }
 
-fn replace_idents<F>(ts: TokenStream, func: &F) -> TokenStream
-		where F: Fn(Ident) -> Ident {
-	let mut out = TokenStream::new();
-	ts.into_iter()
-		.map(move |tt| {
-			match tt {
-				TokenTree::Group(g) => {
-					let delimiter = g.delimiter();
-					let ts = g.stream();
-					let out = replace_idents(ts, func);
-					TokenTree::Group(Group::new(delimiter, out))
-				},
-				TokenTree::Ident(i) => TokenTree::Ident(func(i)),
-				v => v,
-			}
-		})
-		.for_each(|tt| out.append(tt));
-	out
-}
\ No newline at end of file
+/// The same as `def_mod!`, except that the modules aren't declared, only checked, as they're already declared by hand.
+/// Each module is treated as if it had `#[root = "self"]`, unless it was given a root of its own.
+#[proc_macro]
+pub fn def_mod_check(tokens: TStream) -> TStream {
+	def_mod_core::expand(tokens.into(), false).into()
+}
@@ -0,0 +1,60 @@
+use def_mod_core::{convert, DeclItem, ModuleDecl};
+use quote::ToTokens;
+use syn::{parse_quote, Visibility};
+
+#[test]
+fn build_module() {
+	let mut module = ModuleDecl::new(Visibility::Inherited, parse_quote!(my_mod));
+	module.push_item(Visibility::Inherited, parse_quote!(fn method(_: u32) -> u8;));
+	let parsed: ModuleDecl = parse_quote! {
+		mod my_mod {
+			fn method(_: u32) -> u8;
+		}
+	};
+	assert_eq!(module.into_token_stream().to_string(), parsed.into_token_stream().to_string());
+}
+
+#[test]
+fn build_module_from_fields() {
+	let mut module: ModuleDecl = parse_quote!(pub mod my_mod;);
+	module.ident = parse_quote!(other_mod);
+	if let DeclItem::Type(mut type_item) = parse_quote!(type MyStruct;) {
+		type_item.bounds.push(parse_quote!(Clone));
+		module.push_item(Visibility::Inherited, DeclItem::Type(type_item));
+	}
+	let tokens = module.into_token_stream().to_string();
+	assert!(tokens.contains("pub mod other_mod"), "{}", tokens);
+	assert!(tokens.contains("Clone"), "{}", tokens);
+}
+
+#[test]
+fn diagnostics_outside_of_a_proc_macro() {
+	// `Result` shadows the prelude, which is warned about, and the path isn't checked, as there's no file to check it against.
+	let module: ModuleDecl = parse_quote! {
+		#[cfg(unix)] = "x.rs"
+		mod my_mod {
+			type Result;
+		}
+	};
+	let tokens = module.into_token_stream().to_string();
+	assert!(tokens.contains("deprecated"), "{}", tokens);
+	assert!(!tokens.contains("compile_error"), "{}", tokens);
+}
+
+#[test]
+fn errors_outside_of_a_proc_macro() {
+	let module: ModuleDecl = parse_quote! {
+		mod my_mod {
+			fn method() {}
+		}
+	};
+	let tokens = module.into_token_stream().to_string();
+	assert!(tokens.contains("compile_error"), "{}", tokens);
+}
+
+#[test]
+fn convert_method() {
+	let tokens = convert(&parse_quote!(self::my_mod), 0, parse_quote!(fn method(_: u32) -> u8;)).to_string();
+	assert!(tokens.contains("_ASSERT_METHOD_0"), "{}", tokens);
+	assert!(tokens.contains("self :: my_mod"), "{}", tokens);
+}
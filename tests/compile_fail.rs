@@ -0,0 +1,7 @@
+// With the `stable` feature, the diagnostics are `compile_error!`s and deprecation warnings, which read differently to the snapshots.
+#[cfg(not(feature = "stable"))]
+#[test]
+fn compile_fail() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/ui/*.rs");
+}
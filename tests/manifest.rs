@@ -0,0 +1,47 @@
+#![cfg(feature = "manifest")]
+
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+
+	pub struct MyStruct;
+
+	impl MyStruct {
+		pub fn new() -> Self {
+			MyStruct
+		}
+	}
+}
+
+def_mod! {
+	#![manifest = "target/def_mod/manifest_test.json"]
+
+	#[root = "self"]
+	mod my_mod {
+		fn method(_: u32) -> u8;
+		pub type MyStruct {
+			fn new() -> Self;
+		}
+	}
+}
+
+#[test]
+fn manifest() {
+	let contents = std::fs::read_to_string("target/def_mod/manifest_test.json").unwrap();
+	let manifest: serde_json::Value = serde_json::from_str(&contents).unwrap();
+	let (key, modules) = manifest.as_object()
+		.and_then(|entries| entries.iter().find(|(key, _)| key.ends_with("manifest.rs:19")))
+		.expect("The invocation should have an entry");
+	assert!(key.starts_with("tests"));
+
+	let module = &modules[0];
+	assert_eq!(module["name"], "my_mod");
+	assert_eq!(module["items"][0]["kind"], "fn");
+	assert_eq!(module["items"][0]["name"], "method");
+	assert_eq!(module["items"][1]["kind"], "type");
+	assert_eq!(module["items"][1]["vis"], "pub");
+	assert_eq!(module["items"][1]["items"][0]["name"], "new");
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "testing")]
+
+use def_mod_core::{render, ModuleDecl};
+use syn::parse_quote;
+
+#[test]
+fn render_module() {
+	let module: ModuleDecl = parse_quote! {
+		mod my_mod {
+			fn method(_: u32) -> u8;
+			type MyStruct {
+				fn new() -> Self;
+			}
+			type MyWrapper<T: Clone> {
+				fn get(&self) -> &T;
+			}
+			trait MyTrait {
+				fn name(&self) -> &'static str;
+			}
+		}
+	};
+	let rendered = render(&[module]);
+	let expected = include_str!("render/my_mod.rs");
+	assert_eq!(rendered, expected, "The rendered checks don't match the snapshot:\n{}", rendered);
+}
+
+#[test]
+fn render_empty_module() {
+	// There's nothing to check, so it's only declared, without a load function.
+	let module: ModuleDecl = parse_quote!(mod my_mod {});
+	assert_eq!(render(&[module]), "mod my_mod;\n");
+}
+
+#[test]
+fn render_warning() {
+	// Outside of a proc macro, the warning is rendered along with the checks.
+	let module: ModuleDecl = parse_quote! {
+		#[cfg(unix)] = "sys/nix.rs"
+		mod my_mod {
+			type Result;
+		}
+	};
+	let rendered = render(&[module]);
+	let expected = include_str!("render/warning.rs");
+	assert_eq!(rendered, expected, "The rendered checks don't match the snapshot:\n{}", rendered);
+}
+
+#[test]
+fn render_error() {
+	// The method isn't checked, as the body is an error.
+	let module: ModuleDecl = parse_quote! {
+		mod my_mod {
+			fn method() {}
+		}
+	};
+	let rendered = render(&[module]);
+	let expected = include_str!("render/error.rs");
+	assert_eq!(rendered, expected, "The rendered checks don't match the snapshot:\n{}", rendered);
+}
+
+#[test]
+fn render_prefix() {
+	let modules: Vec<ModuleDecl> = vec![
+		parse_quote! {
+			#[prefix = "__dm_"]
+			#[sealed(LegacyStruct)]
+			mod my_mod {
+				#[error = "method must be fn(u32) -> u8"]
+				fn method(_: u32) -> u8;
+				unsafe fn unsafe_method(_: *const u8) -> u8;
+				#[strict_lifetimes]
+				fn strict_method<'a>(_: &'a str, _: &'a str) -> !;
+				#[check_names]
+				async fn named(value: u32) -> u8;
+				pub fn visible(_: u32) -> u8;
+				pub(crate) type Visible;
+				type MyStruct {
+					async fn run(&self) -> u32;
+				}
+				trait MyTrait {
+					type Item: Clone;
+				}
+			}
+		},
+		parse_quote! {
+			#[prefix = "__dm_"]
+			#[object_safe(MyPlugin)]
+			mod my_plugin_mod {
+				fn name(&self) -> &'static str;
+			}
+		},
+	];
+	let rendered = render(&modules);
+	// Other than the parameters, generic ones included, everything that's named starts with the prefix.
+	let unprefixed = rendered.split(|c: char| !(c == '_' || c.is_ascii_alphanumeric()))
+		.filter(|name| name.starts_with('_') && !name.starts_with("__dm_"))
+		.filter(|name| !matches!(*name, "_" | "_self" | "_Self" | "_split") && !name.starts_with("_arg"))
+		.collect::<Vec<_>>();
+	assert!(unprefixed.is_empty(), "{:?} aren't prefixed:\n{}", unprefixed, rendered);
+	let expected = include_str!("render/prefix.rs");
+	assert_eq!(rendered, expected, "The rendered checks don't match the snapshot:\n{}", rendered);
+}
@@ -0,0 +1,7 @@
+mod my_mod;
+#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+fn _load_my_mod() {
+	use self::my_mod::*;
+	const _ASSERT_METHOD_0: fn() = self::my_mod::method;
+}
+compile_error!{"A body isn't valid here, only the signature is checked. [Hint: Replace the body with `;`.]"}
@@ -0,0 +1,30 @@
+mod my_mod;
+#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+fn _load_my_mod() {
+	use self::my_mod::*;
+	const _ASSERT_METHOD_0: fn(_: u32) -> u8 = self::my_mod::method;
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{MyStruct};
+		const _ASSERT_METHOD_1: fn() -> MyStruct = MyStruct::new;
+	}
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{MyWrapper};
+		#[allow(non_snake_case)]
+		fn _check_MyWrapper<T: Clone>() {
+			let _ = ::core::marker::PhantomData::<MyWrapper<T>>;
+			#[allow(non_snake_case)]
+			let _ASSERT_METHOD_2: fn(_self: &MyWrapper<T>) -> &T = MyWrapper::get;
+		}
+	}
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{MyTrait};
+		#[allow(non_snake_case)]
+		fn _check_MyTrait<_Self: MyTrait>() {
+			#[allow(non_snake_case)]
+			let _ASSERT_METHOD_3: fn(_self: &_Self) -> &'static str = _Self::name;
+		}
+	}
+}
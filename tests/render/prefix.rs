@@ -0,0 +1,132 @@
+mod my_mod;
+#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+fn __dm_load_my_mod() {
+	use self::my_mod::*;
+	{
+		#[diagnostic::on_unimplemented(message = "method must be fn(u32) -> u8")]
+		trait __dm_AssertMethod0 {}
+		impl __dm_AssertMethod0 for fn(_: u32) -> u8 {}
+		fn __dm_assert<F: __dm_AssertMethod0>(_: F) {}
+		let __dm_method: fn(_) -> _ = self::my_mod::method;
+		__dm_assert(__dm_method);
+	}
+	const __dm_ASSERT_METHOD_1: unsafe fn(_: *const u8) -> u8 = self::my_mod::unsafe_method;
+	{
+		trait __dm_MustBeUnsafe<Marker> {}
+		impl<F> __dm_MustBeUnsafe<()> for F {}
+		impl<F: Fn(*const u8) -> R, R> __dm_MustBeUnsafe<(R,)> for F {}
+		fn __dm_assert_unsafe<F: __dm_MustBeUnsafe<Marker>, Marker>(_: F) {}
+		__dm_assert_unsafe(self::my_mod::unsafe_method);
+	}
+	const __dm_ASSERT_METHOD_2: for<'a> fn(_: &'a str, _: &'a str) -> ! = self::my_mod::strict_method;
+	{
+		{
+			trait __dm_Returns {
+				type Output;
+			}
+			impl<T> __dm_Returns for fn() -> T {
+				type Output = T;
+			}
+			trait __dm_Strict<'a, '_split, Marker> {}
+			impl<'a, '_split, F> __dm_Strict<'a, '_split, ()> for F {}
+			impl<'a, '_split, F: Fn(&'_split str, &'a str) -> <fn() -> ! as __dm_Returns>::Output> __dm_Strict<'a, '_split, (u8,)> for F {}
+			fn __dm_assert_strict<F: for<'a, '_split> __dm_Strict<'a, '_split, Marker>, Marker>(_: F) {}
+			__dm_assert_strict(self::my_mod::strict_method);
+		}
+		{
+			trait __dm_Returns {
+				type Output;
+			}
+			impl<T> __dm_Returns for fn() -> T {
+				type Output = T;
+			}
+			trait __dm_Strict<'a, '_split, Marker> {}
+			impl<'a, '_split, F> __dm_Strict<'a, '_split, ()> for F {}
+			impl<'a, '_split, F: Fn(&'a str, &'_split str) -> <fn() -> ! as __dm_Returns>::Output> __dm_Strict<'a, '_split, (u8,)> for F {}
+			fn __dm_assert_strict<F: for<'a, '_split> __dm_Strict<'a, '_split, Marker>, Marker>(_: F) {}
+			__dm_assert_strict(self::my_mod::strict_method);
+		}
+	}
+	#[allow(non_snake_case)]
+	fn __dm_assert_async_3(_arg0: u32) {
+		fn __dm_takes_future<F: ::core::future::Future<Output = u8>>(_: F) {}
+		__dm_takes_future(self::my_mod::named(_arg0));
+	}
+	#[allow(non_snake_case)]
+	fn __dm_assert_names_3(value: u32) {
+		#[allow(unused_variables)]
+		let __dm_future = self::my_mod::named(value);
+	}
+	{
+		#[allow(unused_imports)]
+		mod __dm_check_vis {
+			pub use super::my_mod::visible;
+		}
+	}
+	const __dm_ASSERT_METHOD_4: fn(_: u32) -> u8 = self::my_mod::visible;
+	{
+		#[allow(unused_imports)]
+		mod __dm_check_vis {
+			pub(crate) use super::my_mod::Visible;
+		}
+	}
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{Visible};
+	}
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{MyStruct};
+		#[allow(non_snake_case)]
+		fn __dm_assert_async_5(_arg0: &MyStruct) {
+			let __dm_receiver = {
+				#[allow(clippy::empty_loop)]
+				fn __dm_infer<X>() -> X {loop {}}
+				__dm_infer()
+			};
+			{
+				#[diagnostic::on_unimplemented(message = "The receiver is `{Self}`, but it was declared as `{X}`")]
+				trait __dm_Is<X> {}
+				impl<X> __dm_Is<X> for X {}
+				fn __dm_assert_is<X: __dm_Is<Y>, Y>(_: &X) {}
+				__dm_assert_is::<_, &MyStruct>(&__dm_receiver);
+			}
+			fn __dm_takes_future<F: ::core::future::Future<Output = u32>>(_: F) {}
+			__dm_takes_future(MyStruct::run(__dm_receiver));
+		}
+	}
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{MyTrait};
+		#[allow(non_snake_case)]
+		fn __dm_check_MyTrait<_Self: MyTrait>() {
+			{
+				fn __dm_assert_assoc<T: ?Sized + Clone>() {}
+				__dm_assert_assoc::<<_Self as MyTrait>::Item>();
+			}
+		}
+	}
+	#[allow(unused_imports, non_camel_case_types, dead_code)]
+	mod __dm_sealed {
+		use super::my_mod::*;
+		use self::__dm_decoys::*;
+		mod __dm_decoys {
+			pub struct LegacyStruct;
+		}
+		fn __dm_check() {
+			let _: LegacyStruct = LegacyStruct;
+		}
+	}
+}
+mod my_plugin_mod;
+#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+fn __dm_load_my_plugin_mod() {
+	use self::my_plugin_mod::*;
+	#[allow(unused_parens)]
+	{
+		#[allow(unused_imports)]
+		use self::my_plugin_mod::{MyPlugin};
+		fn __dm_assert_obj(_: &dyn MyPlugin) {}
+		const __dm_ASSERT_METHOD_0: fn(_self: &(dyn MyPlugin + 'static)) -> &'static str = <dyn MyPlugin + 'static>::name;
+	}
+}
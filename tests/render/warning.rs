@@ -0,0 +1,18 @@
+#[cfg(unix)]
+#[path = "sys/nix.rs"]
+mod my_mod;
+#[cfg(any(unix))]
+#[allow(dead_code, deprecated, nonstandard_style, clippy::type_complexity, clippy::multiple_bound_locations)]
+fn _load_my_mod() {
+	use self::my_mod::*;
+	{
+		#[allow(unused_imports)]
+		use self::my_mod::{Result};
+	}
+}
+const _: () = {
+	#[deprecated(note = "def_mod: `Result` is also in the prelude, and the module's glob import shadows it, so any signature that names it means the module's instead. [Hint: Use #[no_glob] if they mean the prelude's.]")]
+	#[allow(non_upper_case_globals)]
+	const warning: () = ();
+	warning
+};
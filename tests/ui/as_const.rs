@@ -0,0 +1,34 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn handler(value: u8) -> u8 {
+		value
+	}
+
+	pub const HANDLER: fn(u16) -> u8 = |value| value as u8;
+
+	pub fn convert<T: Into<u8>>(value: T) -> u8 {
+		value.into()
+	}
+
+	pub const CONVERT: fn(u8) -> u8 = convert::<u8>;
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		fn handler(_: u8) -> u8 as const HANDLER;
+		fn convert<T: Into<u8>>(_: T) -> u8 as const CONVERT;
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type Handler {
+			fn new() -> Self as const NEW;
+		}
+	}
+}
+
+fn main() {}
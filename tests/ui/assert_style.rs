@@ -0,0 +1,37 @@
+use def_mod::def_mod;
+
+mod const_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+mod let_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+mod unknown_mod {
+}
+
+def_mod! {
+	#[root = "self"]
+	#[assert_style = "const"]
+	mod const_mod {
+		fn method(_: u32) -> u16;
+	}
+
+	#[root = "self"]
+	#[assert_style = "let"]
+	mod let_mod {
+		fn method(_: u32) -> u16;
+	}
+
+	#[root = "self"]
+	#[assert_style = "static"]
+	mod unknown_mod {
+	}
+}
+
+fn main() {}
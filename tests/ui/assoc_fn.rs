@@ -0,0 +1,31 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct MyStruct {
+		pub items: Vec<u8>,
+	}
+
+	impl MyStruct {
+		pub fn with_capacity(capacity: usize) -> MyStruct {
+			MyStruct {
+				items: Vec::with_capacity(capacity),
+			}
+		}
+
+		pub fn len(&self) -> usize {
+			self.items.len()
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		fn MyStruct::with_capacity(_: u32) -> MyStruct;
+		fn MyStruct::len(&self) -> usize;
+		fn MyStruct::empty() -> MyStruct;
+		fn MyStruct::new() -> Self;
+	}
+}
+
+fn main() {}
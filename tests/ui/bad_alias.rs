@@ -0,0 +1,9 @@
+use def_mod::def_mod;
+
+def_mod! {
+	#[cfg(all())] = "modules/sys.rs"
+	#[cfg(any())] = "~missing"
+	mod sys;
+}
+
+fn main() {}
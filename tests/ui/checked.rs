@@ -0,0 +1,19 @@
+use def_mod::def_mod_check;
+
+mod checked_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+def_mod_check! {
+	mod checked_mod {
+		fn method(_: u32) -> u16;
+	}
+
+	#[cfg(unix)] = "unix.rs"
+	mod pathed_mod {
+	}
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn double(value: u8) -> u8 {
+		value * 2
+	}
+
+	pub const HANDLER: fn(u8) -> u8 = double;
+
+	pub struct MyStruct;
+
+	impl MyStruct {
+		pub fn get(&self) -> u8 {
+			0
+		}
+
+		pub const GET: fn(&Self) -> u8 = Self::get;
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// Each of these is declared with a different signature than the stored pointer.
+		const HANDLER: fn(u16) -> u8;
+
+		type MyStruct {
+			const GET: fn(&mut Self) -> u8;
+		}
+	}
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct Coords {
+		pub value: f64,
+	}
+
+	pub struct Private(f64);
+
+	pub struct Meters(pub f64);
+
+	pub struct Unit;
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type Coords {
+			tuple(f64);
+		}
+		type Private {
+			tuple(f64);
+		}
+		type Meters {
+			tuple(f32);
+			unit;
+		}
+		type Unit {
+			tuple();
+		}
+	}
+}
+
+fn main() {}
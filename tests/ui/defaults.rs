@@ -0,0 +1,24 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct Buffer<T = u16> {
+		pub data: Vec<T>,
+	}
+
+	pub type Bytes<T = u16> = Vec<T>;
+
+	pub fn make<T>() -> Vec<T> {
+		Vec::new()
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type Buffer<T = u8>;
+		type Bytes<T = u8> = Vec<T>;
+		fn make<T = u8>() -> Vec<T>;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn abort() {}
+
+	pub fn exit() -> ! {
+		panic!()
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// A function that returns can't stand in for a diverging one, nor the other way around.
+		fn abort() -> !;
+		fn exit();
+	}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use def_mod::def_mod_check;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+def_mod_check! {
+	#[expect_cfg(windows, target_pointer_width = "64")]
+	mod my_mod {
+		fn method(_: u32) -> u8;
+	}
+}
+
+fn main() {}
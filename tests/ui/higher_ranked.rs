@@ -0,0 +1,21 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn apply(func: for<'a> fn(&'a str) -> &'a str) -> usize {
+		func("value").len()
+	}
+
+	pub fn visit(func: impl for<'a> Fn(&'a u8)) {
+		func(&0)
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		fn apply(_: fn(&'static str) -> &'static str) -> usize;
+		fn visit(_: impl Fn(&'static u8));
+	}
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub trait Encoder {
+		fn finish(self) -> u8;
+
+		fn flush(&mut self) {}
+	}
+
+	pub struct MyStruct;
+
+	impl Encoder for MyStruct {
+		fn finish(self) -> u8 {
+			0
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		impl Encoder for MyStruct {
+			fn finish(self) -> u32;
+			fn flush(&mut self);
+			fn reset(&mut self);
+		}
+	}
+}
+
+fn main() {}
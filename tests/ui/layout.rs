@@ -0,0 +1,33 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	#[repr(C)]
+	pub struct Packet {
+		pub id: u32,
+		pub len: u16,
+		pub kind: u8,
+	}
+
+	pub struct Wrapper<T>(pub T);
+
+	pub struct Marker(pub u8);
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		#[repr(C)]
+		type Packet {
+			size = 12, align = 4
+		}
+		type Wrapper<T> {
+			size = 4;
+		}
+		#[size = 0]
+		type Marker;
+		#[size = "0"]
+		type Packet;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// The body is an error, but the signature is still checked.
+		fn method(_: u32) -> u8 {
+			0
+		}
+	}
+}
+
+fn main() {}
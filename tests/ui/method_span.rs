@@ -0,0 +1,23 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct MyStruct;
+
+	impl MyStruct {
+		pub fn method(&self, _: u32) -> u8 {
+			0
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type MyStruct {
+			// The mismatch points at this declaration, rather than the type or the macro.
+			fn method(&self, _: u64) -> u8;
+		}
+	}
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use def_mod::def_mod;
+
+def_mod! {
+	#[cfg(all())] = ["modules/missing.rs", "modules/other.rs"]
+	#[cfg(any())] = "modules/sys.rs"
+	mod sys;
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		fn missing(_: u32) -> u8;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct MyStruct;
+
+	impl From<u32> for MyStruct {
+		fn from(_: u32) -> Self {
+			MyStruct
+		}
+	}
+
+	pub struct Wrapper<T>(pub T);
+
+	impl<T: Clone> From<T> for Wrapper<T> {
+		fn from(value: T) -> Self {
+			Wrapper(value)
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		impl From<u32> for MyStruct;
+		impl std::fmt::Display for MyStruct;
+		impl From<u64> for MyStruct;
+		// The implementation is only there for some of the parameters.
+		impl<T> From<T> for Wrapper<T>;
+		impl<T: Clone> From<T> for Wrapper<T>;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use def_mod::def_mod;
+
+mod my_mod {
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type MissingType;
+		type MissingGeneric<T>;
+		type MissingMethods {
+			fn new() -> Self;
+		}
+		trait MissingTrait;
+		enum MissingEnum {
+			Variant,
+		}
+		use missing_use;
+		macro missing_macro;
+		mod missing_mod;
+	}
+}
+
+fn main() {}
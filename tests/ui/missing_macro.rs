@@ -0,0 +1,25 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	#[allow(unused_macros)]
+	macro_rules! exported {
+		() => {};
+	}
+	pub(crate) use exported;
+
+	// Without a re-export, this can't be named by a path.
+	#[allow(unused_macros)]
+	macro_rules! hidden {
+		() => {};
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		macro exported;
+		macro hidden;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use def_mod::def_mod;
+
+def_mod! {
+	// Only the path of the active declaration is checked, so the other one doesn't error.
+	#[cfg(unix)] = "modules/sys_typo.rs"
+	#[cfg(not(unix))] = "modules/other_typo.rs"
+	mod sys;
+
+	#[cfg(all())] = "modules/sys.rs"
+	mod existing;
+}
+
+fn main() {}
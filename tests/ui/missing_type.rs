@@ -0,0 +1,15 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct MyStruct;
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type MyStruct;
+		type Missing;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use def_mod::def_mod;
+
+mod my_mod {
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type MissingAlias = u32;
+		const MISSING_CONST: u32;
+		static MISSING_STATIC: u32;
+		use fn missing_use_fn;
+		fn missing_fn(_: u32) -> u8;
+		extern "C" {
+			fn missing_extern();
+		}
+	}
+}
+
+fn main() {}
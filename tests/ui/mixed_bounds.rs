@@ -0,0 +1,22 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub trait MyTrait {}
+
+	pub fn owned<T: Clone + MyTrait + Send + 'static>(_: T) {}
+
+	pub fn shared<T: Clone + MyTrait>(_: T) {}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		trait MyTrait;
+
+		// Each of the bounds is kept, so the one that's missing is still what's reported.
+		fn owned<T: Clone + MyTrait + 'static>(_: T);
+		fn shared<T: Clone + MyTrait + 'static>(_: T);
+	}
+}
+
+fn main() {}
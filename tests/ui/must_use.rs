@@ -0,0 +1,44 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct Guard;
+
+	#[must_use]
+	pub fn acquire() -> Guard {
+		Guard
+	}
+
+	pub fn release(_: u8) -> Guard {
+		Guard
+	}
+
+	impl Guard {
+		#[must_use]
+		pub fn split(&self) -> Guard {
+			Guard
+		}
+
+		pub fn join(&self, _: Guard) -> Guard {
+			Guard
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		#[must_use]
+		fn acquire() -> Guard;
+		#[must_use]
+		fn release(_: u8) -> Guard;
+
+		type Guard {
+			#[must_use]
+			fn split(&self) -> Self;
+			#[must_use]
+			fn join(&self, _: Self) -> Self;
+		}
+	}
+}
+
+fn main() {}
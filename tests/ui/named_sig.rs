@@ -0,0 +1,34 @@
+use def_mod::def_mod;
+
+mod codec {
+	pub fn encode(value: u8) -> u16 {
+		value as u16
+	}
+
+	pub fn decode(value: u16) -> u8 {
+		value as u8
+	}
+
+	pub mod raw {
+		pub fn encode(value: u8) -> u16 {
+			value as u16
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod codec {
+		sig Codec = fn(u8) -> u16;
+		fn encode: Codec;
+		fn decode: Codec;
+		fn missing: Unknown;
+
+		// A nested module can use the signatures of its parents.
+		mod raw {
+			fn encode: Codec;
+		}
+	}
+}
+
+fn main() {}
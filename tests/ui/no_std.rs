@@ -0,0 +1,59 @@
+use def_mod::def_mod;
+
+mod embedded {
+	pub fn name() -> String {
+		String::new()
+	}
+
+	pub fn sizes() -> Vec<usize> {
+		vec![]
+	}
+
+	pub fn error() -> std::fmt::Error {
+		std::fmt::Error
+	}
+
+	pub fn count() -> u32 {
+		0
+	}
+}
+
+mod listed {
+	pub fn name() -> String {
+		String::new()
+	}
+
+	pub fn sizes() -> Vec<usize> {
+		vec![]
+	}
+}
+
+mod unknown {
+}
+
+def_mod! {
+	#[root = "self"]
+	#[no_std]
+	mod embedded {
+		fn name() -> String;
+		fn sizes() -> Vec<usize>;
+		fn error() -> std::fmt::Error;
+		// The lint only warns, so this is what stops the build.
+		fn count() -> u64;
+	}
+
+	// Only the listed types are looked for.
+	#[root = "self"]
+	#[no_std(Vec)]
+	mod listed {
+		fn name() -> String;
+		fn sizes() -> Vec<usize>;
+	}
+
+	#[root = "self"]
+	#[no_std = "String"]
+	mod unknown {
+	}
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use def_mod::def_mod;
+
+mod safe_mod {
+	pub trait Plugin {
+		fn name(&self) -> &'static str;
+
+		fn run(&mut self, _: u32) -> bool;
+
+		fn finish(self) where Self: Sized {}
+
+		fn create() -> Self where Self: Sized;
+	}
+}
+
+mod unsafe_mod {
+	pub trait Plugin {
+		fn name(&self) -> &'static str;
+
+		fn convert<T>(&self, _: T) -> T;
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	#[object_safe(Plugin)]
+	mod safe_mod {
+		fn name(&self) -> &'static str;
+		// The receiver is different from the real one.
+		fn run(&self, _: u32) -> bool;
+		fn finish(self);
+		fn create() -> Self;
+	}
+
+	// A generic method can't be called on a trait object, so the trait isn't object safe.
+	#[root = "self"]
+	#[object_safe(Plugin)]
+	mod unsafe_mod {
+		fn name(&self) -> &'static str;
+	}
+}
+
+fn main() {}
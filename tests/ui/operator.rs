@@ -0,0 +1,34 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	use std::ops::Index;
+
+	pub struct Vec2(pub f32, pub f32);
+
+	impl Vec2 {
+		pub fn add(self, other: Vec2) -> Vec2 {
+			Vec2(self.0 + other.0, self.1 + other.1)
+		}
+	}
+
+	impl Index<usize> for Vec2 {
+		type Output = f32;
+
+		fn index(&self, index: usize) -> &f32 {
+			if index == 0 { &self.0 } else { &self.1 }
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type Vec2 {
+			op Add(self, Self) -> Self;
+			op Index(&self, u8) -> &f32;
+			op Append(self, Self) -> Self;
+		}
+	}
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use def_mod::def_mod;
+
+def_mod! {
+	#[cfg(unix)] = "modules/sys.rs"
+	#[cfg(not(windows))] = "modules/sys.rs"
+	mod sys {
+		fn method(_: u32) -> u8;
+	}
+}
+
+fn main() {}
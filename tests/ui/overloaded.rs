@@ -0,0 +1,27 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn convert(value: u8) -> u16 {
+		value as u16
+	}
+
+	pub fn size() -> usize {
+		0
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		fn convert(_: u8) -> u16;
+		fn convert(_: u16) -> u32;
+
+		// A function that's gated differently each time is fine, as only one of them is ever there.
+		#[cfg(target_pointer_width = "64")]
+		fn size() -> usize;
+		#[cfg(not(target_pointer_width = "64"))]
+		fn size() -> usize;
+	}
+}
+
+fn main() {}
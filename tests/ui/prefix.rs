@@ -0,0 +1,29 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+mod other {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	#[prefix = "1dm_"]
+	mod my_mod {
+		fn method(_: u32) -> u8;
+	}
+
+	#[root = "self"]
+	#[prefix]
+	mod other {
+		fn method(_: u32) -> u8;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use def_mod::def_mod;
+
+mod globbed {
+	pub fn drop(_: u32) {}
+
+	pub struct Result;
+
+	pub fn count() -> u32 {
+		0
+	}
+}
+
+mod unglobbed {
+	pub fn drop(_: u32) {}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod globbed {
+		fn drop(_: u32);
+		type Result;
+		// The lint only warns, so this is what stops the build.
+		fn count() -> u64;
+	}
+
+	// Nothing is imported, so nothing is shadowed.
+	#[root = "self"]
+	#[no_glob]
+	mod unglobbed {
+		fn drop(_: u32);
+	}
+}
+
+fn main() {}
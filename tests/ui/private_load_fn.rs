@@ -0,0 +1,19 @@
+mod outer {
+	use def_mod::def_mod;
+
+	pub mod my_mod {
+		pub fn method() {}
+	}
+
+	def_mod! {
+		#[root = "self"]
+		pub mod my_mod {
+			fn method();
+		}
+	}
+}
+
+fn main() {
+	// The module is public, but the function that loads it isn't.
+	outer::_load_my_mod();
+}
@@ -0,0 +1,54 @@
+use def_mod::def_mod;
+use std::pin::Pin;
+use std::rc::Rc;
+
+mod my_mod {
+	pub struct MyStruct;
+
+	impl MyStruct {
+		pub fn get(&self) -> u8 {
+			0
+		}
+
+		pub fn clear(&mut self) {}
+
+		pub fn into_inner(self) -> u8 {
+			0
+		}
+
+		pub fn iter(&self) -> impl Iterator<Item = u8> {
+			std::iter::empty()
+		}
+
+		pub async fn load(&self) -> u8 {
+			0
+		}
+
+		pub fn boxed(self: Box<Self>) -> u8 {
+			0
+		}
+
+		pub fn pinned(self: std::pin::Pin<&mut Self>) -> u8 {
+			0
+		}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type MyStruct {
+			// Each of these is declared with a different receiver than the real one.
+			fn get(&mut self) -> u8;
+			fn clear(&self);
+			fn into_inner(&self) -> u8;
+			fn boxed(self: Rc<Self>) -> u8;
+			fn pinned(self: Pin<&Self>) -> u8;
+			// These are called through a wrapper, so the receiver is checked separately.
+			fn iter(&mut self) -> impl Iterator<Item = u8>;
+			async fn load(&mut self) -> u8;
+		}
+	}
+}
+
+fn main() {}
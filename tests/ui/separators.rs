@@ -0,0 +1,36 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn first() -> u8 {
+		0
+	}
+
+	pub fn second() -> u8 {
+		0
+	}
+
+	pub struct MyStruct {
+		pub value: u32,
+		pub other: u32,
+	}
+
+	pub const MAX: usize = 0;
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// A missing `;` is reported on the item, and the rest is still checked.
+		fn first() -> u8
+		fn second() -> u16;
+
+		type MyStruct {
+			field value: u32,
+			field other: u32;
+		},
+
+		const MAX: usize
+	}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub static COUNTER: u32 = 0;
+	pub static mut TOTAL: u32 = 0;
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		static mut COUNTER: u32;
+		static TOTAL: u32;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	// The second parameter isn't tied to the output, so it's more general than the declaration.
+	pub fn first<'a, 'b>(a: &'a str, _: &'b str) -> &'a str {
+		a
+	}
+
+	pub fn second<'a, 'b>(_: &'a str, b: &'b str) -> &'b str {
+		b
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// Without the attribute, the more general function passes.
+		fn first<'a>(_: &'a str, _: &'a str) -> &'a str;
+		#[strict_lifetimes]
+		fn second<'a>(_: &'a str, _: &'a str) -> &'a str;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub mod nested {
+		pub fn method() {}
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// The parent declares this module, so there's no declaration to put the attribute on.
+		#[mod: doc(hidden)]
+		#[load: allow(unused)]
+		mod nested {
+			fn method();
+		}
+	}
+}
+
+fn main() {}
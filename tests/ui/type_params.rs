@@ -0,0 +1,19 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub struct Strict<T: Copy> {
+		pub value: T,
+	}
+
+	pub struct Pair<A, B>(pub A, pub B);
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		type Strict<T: Clone>;
+		type Pair<A>;
+	}
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub union Reg {
+		pub word: u32,
+		pub bytes: [u8; 4],
+	}
+
+	pub struct Pair {
+		pub low: u16,
+		pub high: u16,
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		union Reg {
+			word: u32,
+			bytes: [u16; 2],
+		}
+		union Pair {
+			low: u16,
+		}
+	}
+}
+
+fn main() {}
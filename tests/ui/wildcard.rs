@@ -0,0 +1,22 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn merge(base: u32, other: u32) -> u32 {
+		base + other
+	}
+
+	pub const fn limit(value: u32) -> u32 {
+		value
+	}
+}
+
+def_mod! {
+	#[root = "self"]
+	mod my_mod {
+		// The types are left out, but there's still one parameter too few.
+		fn merge(_) -> _;
+		const fn limit(_) -> u32;
+	}
+}
+
+fn main() {}
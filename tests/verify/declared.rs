@@ -0,0 +1,30 @@
+use def_mod::def_mod;
+
+mod my_mod {
+	pub fn method(_: u32) -> u8 {
+		0
+	}
+
+	pub struct MyStruct;
+
+	impl MyStruct {
+		pub fn new() -> Self {
+			MyStruct
+		}
+	}
+}
+
+// Without the variable, this would declare `my_mod` a second time.
+def_mod! {
+	mod my_mod {
+		fn method(_: u32) -> u8;
+		type MyStruct {
+			fn new() -> Self;
+		}
+	}
+}
+
+fn main() {
+	let _ = my_mod::MyStruct::new();
+	assert_eq!(my_mod::method(0), 0);
+}
@@ -0,0 +1,7 @@
+// The variable is read while the fixtures are compiled, which happens in a separate process that inherits it.
+#[test]
+fn verify_only() {
+	std::env::set_var("DEF_MOD_VERIFY_ONLY", "1");
+	let t = trybuild::TestCases::new();
+	t.pass("tests/verify/*.rs");
+}